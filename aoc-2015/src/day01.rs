@@ -0,0 +1,46 @@
+use std::ops::ControlFlow;
+
+use aoc_core::{AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, AocError> {
+        Ok(input
+            .chars()
+            .filter_map(|next_move| match next_move {
+                '(' => Some(1),
+                ')' => Some(-1),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn part_1(&self, moves: &Vec<i32>) -> Result<i32, AocError> {
+        Ok(moves.iter().sum())
+    }
+
+    fn part_2(&self, moves: &Vec<i32>) -> Result<i32, AocError> {
+        let result = moves
+            .iter()
+            .enumerate()
+            .try_fold(0, |current_floor, (index, next_move)| {
+                match current_floor + next_move {
+                    -1 => ControlFlow::Break((index + 1) as i32),
+                    next_floor => ControlFlow::Continue(next_floor),
+                }
+            });
+        Ok(match result {
+            ControlFlow::Break(index) => index,
+            _ => 0,
+        })
+    }
+}
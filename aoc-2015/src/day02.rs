@@ -0,0 +1,57 @@
+use aoc_core::{
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day02;
+
+type Present = (u32, u32, u32);
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
+
+    type Input = Vec<Present>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Present>, AocError> {
+        Ok(parse::parse_lines(input, parse_line)?)
+    }
+
+    fn part_1(&self, presents: &Vec<Present>) -> Result<u32, AocError> {
+        Ok(presents
+            .iter()
+            .map(|&(length, width, height)| {
+                let areas = [length * width, width * height, height * length];
+                let smallest_side = areas.iter().min().unwrap();
+                let total: u32 = areas.iter().map(|side| side * 2).sum();
+                total + smallest_side
+            })
+            .sum())
+    }
+
+    fn part_2(&self, presents: &Vec<Present>) -> Result<u32, AocError> {
+        Ok(presents
+            .iter()
+            .map(|&(length, width, height)| {
+                let mut sides = [length, width, height];
+                sides.sort();
+                let ribbon_for_wrap = sides[0] * 2 + sides[1] * 2;
+                let ribbon_for_bow = length * width * height;
+                ribbon_for_bow + ribbon_for_wrap
+            })
+            .sum())
+    }
+}
+
+fn parse_line(line: Line) -> Result<Present, ParseError> {
+    let sides: Vec<&str> = line.text.split('x').collect();
+    match sides[..] {
+        [length, width, height] => {
+            Ok((line.parse(length)?, line.parse(width)?, line.parse(height)?))
+        }
+        _ => Err(line.error(line.text, "Expected LxWxH")),
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    geometry::{Heading, Point},
+    AocError, Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+
+    type Input = Vec<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<char>, AocError> {
+        Ok(input.chars().collect())
+    }
+
+    fn part_1(&self, directions: &Vec<char>) -> Result<usize, AocError> {
+        let initial_position = Point::ORIGIN;
+        let (_, mut visited) = directions.iter().fold(
+            (initial_position, HashSet::new()),
+            |(current_position, mut visited), &direction| {
+                let new_position = update_position(current_position, direction);
+                visited.insert(new_position);
+                (new_position, visited)
+            },
+        );
+        visited.insert(initial_position);
+        Ok(visited.len())
+    }
+
+    fn part_2(&self, directions: &Vec<char>) -> Result<usize, AocError> {
+        let santa_initial_position = Point::ORIGIN;
+        let robot_initial_position = Point::ORIGIN;
+        let (_, mut visited) = directions.iter().enumerate().fold(
+            (
+                (santa_initial_position, robot_initial_position),
+                HashSet::new(),
+            ),
+            |((santa_position, robot_position), mut visited), (index, &direction)| {
+                let (new_santa_position, new_robot_position) = if index % 2 == 0 {
+                    (update_position(santa_position, direction), robot_position)
+                } else {
+                    (santa_position, update_position(robot_position, direction))
+                };
+                visited.insert(new_santa_position);
+                visited.insert(new_robot_position);
+                ((new_santa_position, new_robot_position), visited)
+            },
+        );
+        visited.insert(santa_initial_position);
+        Ok(visited.len())
+    }
+}
+
+fn update_position(current_position: Point, direction: char) -> Point {
+    match Heading::try_from(direction) {
+        Ok(heading) => current_position + heading.vector(),
+        Err(_) => current_position,
+    }
+}
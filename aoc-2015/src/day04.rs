@@ -0,0 +1,34 @@
+use aoc_core::{md5::HashSearch, AocError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
+
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<String, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(&self, secret: &String) -> Result<usize, AocError> {
+        Ok(find_lowest_number(secret, 5))
+    }
+
+    fn part_2(&self, secret: &String) -> Result<usize, AocError> {
+        Ok(find_lowest_number(secret, 6))
+    }
+}
+
+fn find_lowest_number(secret: &str, zeros: usize) -> usize {
+    let (number, _) = HashSearch::new(secret, zeros)
+        .starting_at(1)
+        .matches()
+        .next()
+        .expect("The search never ends without a match");
+    number
+}
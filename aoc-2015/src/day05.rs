@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use aoc_core::{AocError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part_1(&self, strings: &Vec<String>) -> Result<usize, AocError> {
+        Ok(strings.iter().filter(|string| is_nice(string)).count())
+    }
+
+    fn part_2(&self, strings: &Vec<String>) -> Result<usize, AocError> {
+        Ok(strings.iter().filter(|string| is_nice_2(string)).count())
+    }
+}
+
+fn is_nice(string: &str) -> bool {
+    let mut vowel_counter = 0;
+    let mut has_repeated = false;
+    let mut last_char: Option<char> = None;
+
+    let mut vowels = HashSet::new();
+    vowels.extend(['a', 'e', 'i', 'o', 'u']);
+
+    let mut bad_words = HashSet::new();
+    bad_words.extend(["ab", "cd", "pq", "xy"]);
+
+    for character in string.chars() {
+        if vowels.contains(&character) {
+            vowel_counter += 1;
+        }
+        if let Some(last_char) = last_char {
+            let combo = format!("{}{}", last_char, character);
+            if bad_words.contains(&combo[..]) {
+                return false;
+            }
+            if character == last_char {
+                has_repeated = true;
+            }
+        }
+        last_char = Some(character);
+    }
+    vowel_counter >= 3 && has_repeated
+}
+
+// fn is_nice_2(string: &str) -> bool {
+//     let string: Vec<char> = string.chars().collect();
+//     let mut contains_character_sandwich = false;
+//     for triplet in string.windows(3) {
+//         if triplet[0] == triplet[2] {
+//             contains_character_sandwich = true;
+//             break;
+//         }
+//     }
+
+//     let mut seen_pairs: HashSet<String> = HashSet::new();
+//     let mut last_pair: Option<String> = None;
+//     let mut has_repeated_pairs = false;
+//     for uno in string.windows(2) {
+//         let pair: String = format!("{}{}", uno[0], uno[1]);
+//         if seen_pairs.contains(&pair) && (pair != last_pair.unwrap()) {
+//             has_repeated_pairs = true;
+//             break
+//         }
+//         seen_pairs.insert(String::from(pair.clone()));
+//         last_pair = Some(String::from(pair.clone()));
+//     }
+//     println!("{}, {}",contains_character_sandwich, has_repeated_pairs);
+//     contains_character_sandwich && has_repeated_pairs
+// }
+fn is_nice_2(string: &str) -> bool {
+    let string: Vec<char> = string.chars().collect();
+    let mut contains_character_sandwich = false;
+    for triplet in string.windows(3) {
+        if triplet[0] == triplet[2] {
+            contains_character_sandwich = true;
+            break;
+        }
+    }
+
+    let mut seen_pairs: HashSet<String> = HashSet::new();
+    let mut last_pair: Option<String> = None;
+    let mut has_repeated_pairs = false;
+    let mut last_pair_times = 0;
+    for uno in string.windows(2) {
+        let pair: String = format!("{}{}", uno[0], uno[1]);
+        if seen_pairs.contains(&pair) && (Some(&pair) != last_pair.as_ref() || last_pair_times > 1)
+        {
+            has_repeated_pairs = true;
+            break;
+        }
+        seen_pairs.insert(pair.clone());
+        if Some(&pair) == last_pair.as_ref() {
+            last_pair_times += 1;
+        } else {
+            last_pair_times = 1;
+        }
+        last_pair = Some(pair);
+    }
+    contains_character_sandwich && has_repeated_pairs
+}
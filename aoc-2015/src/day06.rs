@@ -0,0 +1,98 @@
+use aoc_core::{
+    grid::Grid,
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+
+    type Input = Vec<Instruction>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, AocError> {
+        Ok(parse::parse_lines(input, parse_line)?)
+    }
+
+    fn part_1(&self, instructions: &Vec<Instruction>) -> Result<usize, AocError> {
+        let mut grid = Grid::new(1000, 1000, false);
+        instructions.iter().for_each(|(operation, start, end)| {
+            for position in lights(&grid, start, end) {
+                let light = &mut grid[position];
+                *light = match operation {
+                    Operation::On => true,
+                    Operation::Off => false,
+                    Operation::Toggle => !*light,
+                }
+            }
+        });
+        Ok(grid.values().filter(|&&light| light).count())
+    }
+
+    fn part_2(&self, instructions: &Vec<Instruction>) -> Result<usize, AocError> {
+        let mut grid = Grid::new(1000, 1000, 0usize);
+        instructions.iter().for_each(|(operation, start, end)| {
+            for position in lights(&grid, start, end) {
+                let light = &mut grid[position];
+                *light = match operation {
+                    Operation::On => *light + 1,
+                    Operation::Off => light.saturating_sub(1),
+                    Operation::Toggle => *light + 2,
+                }
+            }
+        });
+        Ok(grid.values().sum())
+    }
+}
+
+#[derive(Debug)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+pub enum Operation {
+    On,
+    Off,
+    Toggle,
+}
+
+type Instruction = (Operation, Point, Point);
+
+/// Positions of the lights between the `start` and `end` corners, both
+/// included.
+fn lights<T>(grid: &Grid<T>, start: &Point, end: &Point) -> impl Iterator<Item = (usize, usize)> {
+    grid.rect((start.x, start.y), end.x + 1 - start.x, end.y + 1 - start.y)
+}
+
+fn parse_line(line: Line) -> Result<Instruction, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(.*) (\d+),(\d+) through (\d+),(\d+)$").unwrap();
+    }
+    let captures = line.captures(&RE)?;
+    let operation = match captures.str(1)? {
+        "turn off" => Operation::Off,
+        "turn on" => Operation::On,
+        "toggle" => Operation::Toggle,
+        operation => return Err(line.error(operation, "Unknown operation")),
+    };
+    let start = Point {
+        x: captures.get(2)?,
+        y: captures.get(3)?,
+    };
+    let end = Point {
+        x: captures.get(4)?,
+        y: captures.get(5)?,
+    };
+    if end.x < start.x || end.y < start.y || end.x >= 1000 || end.y >= 1000 {
+        return Err(line.error(line.text, "The lights aren't within the 1000x1000 grid"));
+    }
+    Ok((operation, start, end))
+}
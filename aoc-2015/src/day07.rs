@@ -0,0 +1,34 @@
+use aoc_core::{
+    circuit::{self, Context, Input, Instruction, Operator},
+    AocError, Solution,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
+
+    type Input = Context;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    fn parse(&self, input: &str) -> Result<Context, AocError> {
+        Ok(Context::new(circuit::parse_instructions(input)?)?)
+    }
+
+    fn part_1(&self, context: &Context) -> Result<u16, AocError> {
+        Ok(context.value("a")?)
+    }
+
+    fn part_2(&self, context: &Context) -> Result<u16, AocError> {
+        let b_wire_override = Instruction {
+            input: Input::Assign(Operator::Literal(self.part_1(context)?)),
+            output: String::from("b"),
+        };
+        let mut context = context.clone();
+        context.override_wires([b_wire_override])?;
+        Ok(context.value("a")?)
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_core::{AocError, Solution};
+use regex::{Captures, Regex};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Matchsticks";
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part_1(&self, lines: &Vec<String>) -> Result<usize, AocError> {
+        let re = Regex::new(r#"(\\x[a-f0-9]{2}){1}|(\\\\){1}|(\\"){1}"#).unwrap();
+        Ok(lines
+            .iter()
+            .map(|line| {
+                let character_count = line.len();
+                let in_memory_character_count = re.replace_all(line, "_").len();
+                character_count - (in_memory_character_count - 2)
+            })
+            .sum())
+    }
+
+    fn part_2(&self, lines: &Vec<String>) -> Result<usize, AocError> {
+        let re = Regex::new(r#"(?P<hex>\\x[a-f0-9]{2}){1}|(?P<back>\\\\){1}|(?P<dquote>\\"){1}"#)
+            .unwrap();
+        let escaped_count: usize = lines
+            .iter()
+            .map(|line| {
+                let escaped = re.replace_all(line, |caps: &Captures| {
+                    if let Some(hex) = &caps.name("hex") {
+                        return format!(r#"\{}"#, hex.as_str());
+                    };
+
+                    if let Some(back) = &caps.name("back") {
+                        return format!(r#"\\{}"#, back.as_str());
+                    };
+
+                    if let Some(dquote) = &caps.name("dquote") {
+                        return format!(r#"\\{}"#, dquote.as_str());
+                    };
+
+                    String::from("")
+                });
+                (escaped.len() + 4) - line.len()
+            })
+            .sum();
+        Ok(escaped_count)
+    }
+}
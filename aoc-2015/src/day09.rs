@@ -0,0 +1,52 @@
+use aoc_core::{
+    parse::{self, ParseError},
+    tsp::{DistanceMatrix, Goal, Shape},
+    AocError, Solution,
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "All in a Single Night";
+
+    type Input = DistanceMatrix;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<DistanceMatrix, AocError> {
+        Ok(parse_distance_map(input)?)
+    }
+
+    fn part_1(&self, distances: &DistanceMatrix) -> Result<i64, AocError> {
+        route_distance(distances, Goal::Minimize)
+    }
+
+    fn part_2(&self, distances: &DistanceMatrix) -> Result<i64, AocError> {
+        route_distance(distances, Goal::Maximize)
+    }
+}
+
+fn route_distance(distances: &DistanceMatrix, goal: Goal) -> Result<i64, AocError> {
+    Ok(distances.solve(Shape::Path, goal)?.cost)
+}
+
+fn parse_distance_map(input: &str) -> Result<DistanceMatrix, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\w+) to (\w+) = (\d+)$").unwrap();
+    }
+    let mut distances = DistanceMatrix::new();
+    for line in parse::lines(input) {
+        let captures = line.captures(&RE)?;
+        let start = captures.str(1)?;
+        let end = captures.str(2)?;
+        let distance = captures.get(3)?;
+
+        distances.set(start, end, distance);
+        distances.set(end, start, distance);
+    }
+    Ok(distances)
+}
@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use aoc_core::{parse, AocError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
+
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<String, AocError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| AocError::invalid("The input is empty"))?;
+        let sequence = line.text.trim();
+        if let Some((offset, character)) = sequence
+            .char_indices()
+            .find(|(_, character)| !character.is_ascii_digit())
+        {
+            let text = &sequence[offset..offset + character.len_utf8()];
+            return Err(line.error(text, "Expected a digit").into());
+        }
+        Ok(sequence.to_string())
+    }
+
+    fn part_1(&self, sequence: &String) -> Result<usize, AocError> {
+        let mut sequence = sequence.clone();
+        for _ in 0..40 {
+            sequence = mutate_sequence(&sequence);
+        }
+        Ok(sequence.len())
+    }
+
+    fn part_2(&self, sequence: &String) -> Result<usize, AocError> {
+        let mut sequence = sequence.clone();
+        for _ in 0..50 {
+            sequence = mutate_sequence(&sequence);
+        }
+        Ok(sequence.len())
+    }
+}
+
+fn mutate_sequence(sequence: &str) -> String {
+    let Some(mut last_character) = sequence.chars().next() else {
+        return String::new();
+    };
+    let mut output = String::new();
+    let mut current_count = 1;
+    sequence.chars().skip(1).for_each(|character| {
+        if last_character != character {
+            write!(output, "{}", current_count).expect("Writing to a String can't fail");
+            output.push(last_character);
+            current_count = 0;
+        }
+        last_character = character;
+        current_count += 1;
+    });
+    write!(output, "{}", current_count).expect("Writing to a String can't fail");
+    output.push(last_character);
+    output
+}
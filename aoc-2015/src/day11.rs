@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use aoc_core::{parse, AocError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Corporate Policy";
+
+    type Input = String;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> Result<String, AocError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| AocError::invalid("The input is empty"))?;
+        let password = line.text.trim();
+        if let Some((offset, character)) = password
+            .char_indices()
+            .find(|(_, character)| !character.is_ascii_lowercase())
+        {
+            let text = &password[offset..offset + character.len_utf8()];
+            return Err(line.error(text, "Expected a lowercase letter").into());
+        }
+        if password.len() != PASSWORD_LENGTH {
+            return Err(line
+                .error(
+                    password,
+                    format!("Passwords are {} letters long", PASSWORD_LENGTH),
+                )
+                .into());
+        }
+        Ok(password.to_string())
+    }
+
+    fn part_1(&self, password: &String) -> Result<String, AocError> {
+        Ok(find_next_valid_password(password))
+    }
+
+    fn part_2(&self, password: &String) -> Result<String, AocError> {
+        Ok(find_next_valid_password(&find_next_valid_password(
+            password,
+        )))
+    }
+}
+
+const PASSWORD_LENGTH: usize = 8;
+
+fn is_valid_password(
+    input: &str,
+    invalid_characters: &HashSet<char>,
+    repeats: &HashSet<[char; 2]>,
+    triplets: &HashSet<[char; 3]>,
+) -> bool {
+    for character in input.chars() {
+        if invalid_characters.contains(&character) {
+            return false;
+        }
+    }
+
+    let mut contains_triplet = false;
+    for triplet in input.chars().collect::<Vec<char>>().windows(3) {
+        if triplets.contains(triplet) {
+            contains_triplet = true;
+            break;
+        }
+    }
+
+    let mut contains_repeated_pair = 0;
+    let mut last_repeat = false;
+    for pair in input.chars().collect::<Vec<char>>().windows(2) {
+        if repeats.contains(pair) && !last_repeat {
+            contains_repeated_pair += 1;
+            last_repeat = true;
+        } else {
+            last_repeat = false;
+        }
+    }
+
+    contains_triplet && (contains_repeated_pair > 1)
+}
+
+fn next_password(password: &str) -> String {
+    if password.is_empty() {
+        return String::from("");
+    }
+    let mut output: Vec<char> = vec![];
+
+    let min_bytechar: u8 = b'a';
+    let max_bytechar: u8 = b'z';
+
+    let mut bytes_iterator = password.bytes().rev();
+    let mut remainder: u8 = 1;
+
+    while remainder > 0 && output.len() < PASSWORD_LENGTH {
+        let sum_bytechar = if let Some(bytechar) = bytes_iterator.next() {
+            bytechar + remainder
+        } else {
+            min_bytechar + remainder
+        };
+        remainder = sum_bytechar.saturating_sub(max_bytechar);
+
+        if remainder > 0 {
+            output.push(min_bytechar as char);
+        } else {
+            output.push(sum_bytechar as char)
+        }
+    }
+
+    for byte_char in bytes_iterator {
+        output.push(byte_char as char)
+    }
+
+    output.into_iter().take(PASSWORD_LENGTH).rev().collect()
+}
+
+fn find_next_valid_password(password: &str) -> String {
+    let repeated_pairs: HashSet<[char; 2]> =
+        HashSet::from_iter(('a'..='z').map(|character| [character, character]));
+
+    let ascending_triplets: HashSet<[char; 3]> = HashSet::from_iter(
+        ('a'..='z')
+            .collect::<Vec<char>>()
+            .windows(3)
+            .map(|triplet| [triplet[0], triplet[1], triplet[2]]),
+    );
+
+    let invalid_characters: HashSet<char> = HashSet::from_iter(['i', 'l', 'o']);
+
+    let mut password: String = password.into();
+
+    loop {
+        password = next_password(&password);
+        if is_valid_password(
+            &password,
+            &invalid_characters,
+            &repeated_pairs,
+            &ascending_triplets,
+        ) {
+            break;
+        }
+    }
+
+    password
+}
@@ -0,0 +1,52 @@
+use aoc_core::{AocError, Solution};
+use serde_json::Value;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+
+    type Input = Value;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Value, AocError> {
+        serde_json::from_str(input)
+            .map_err(|error| AocError::invalid(format!("Invalid JSON: {}", error)))
+    }
+
+    fn part_1(&self, parsed: &Value) -> Result<i64, AocError> {
+        sum_numbers(parsed, false)
+    }
+
+    fn part_2(&self, parsed: &Value) -> Result<i64, AocError> {
+        sum_numbers(parsed, true)
+    }
+}
+
+fn sum_numbers(json_struct: &Value, ignore_reds: bool) -> Result<i64, AocError> {
+    Ok(match json_struct {
+        Value::Number(n) => n
+            .as_i64()
+            .ok_or_else(|| AocError::invalid(format!("{} isn't an integer", n)))?,
+        Value::Array(vec) => vec
+            .iter()
+            .map(|val| sum_numbers(val, ignore_reds))
+            .sum::<Result<i64, AocError>>()?,
+        Value::Object(map) => {
+            let mut total_sum = 0;
+            for val in map.values() {
+                if let Value::String(s) = val {
+                    if ignore_reds && s == "red" {
+                        return Ok(0);
+                    }
+                }
+                total_sum += sum_numbers(val, ignore_reds)?;
+            }
+            total_sum
+        }
+        _ => 0,
+    })
+}
@@ -0,0 +1,59 @@
+use aoc_core::{
+    parse::{self, Line, ParseError},
+    tsp::{DistanceMatrix, Goal, Shape},
+    AocError, Solution,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Knights of the Dinner Table";
+
+    type Input = DistanceMatrix;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    /// Sitting next to each other changes the happiness of both neighbours,
+    /// so both changes are added to the cost of the pair either way.
+    fn parse(&self, input: &str) -> Result<DistanceMatrix, AocError> {
+        let mut happiness = DistanceMatrix::new();
+        for (person, neighbour, change) in parse::parse_lines(input, parse_line)? {
+            happiness.add(person, neighbour, change);
+            happiness.add(neighbour, person, change);
+        }
+        Ok(happiness)
+    }
+
+    fn part_1(&self, happiness: &DistanceMatrix) -> Result<i64, AocError> {
+        maximize_happiness(happiness)
+    }
+
+    fn part_2(&self, happiness: &DistanceMatrix) -> Result<i64, AocError> {
+        let mut happiness = happiness.clone();
+        for guest in happiness.names().to_vec() {
+            happiness.add("me", &guest, 0);
+            happiness.add(&guest, "me", 0);
+        }
+        maximize_happiness(&happiness)
+    }
+}
+
+fn parse_line(line: Line<'_>) -> Result<(&str, &str, i64), ParseError> {
+    let amount: i64 = line.field(3)?;
+    let change = match line.word(2)? {
+        "gain" => amount,
+        "lose" => -amount,
+        word => return Err(line.error(word, "Expected gain or lose")),
+    };
+    let neighbour = line.word(10)?;
+    let neighbour = neighbour
+        .strip_suffix('.')
+        .ok_or_else(|| line.error(neighbour, "Missing final period"))?;
+    Ok((line.word(0)?, neighbour, change))
+}
+
+fn maximize_happiness(happiness: &DistanceMatrix) -> Result<i64, AocError> {
+    Ok(happiness.solve(Shape::Cycle, Goal::Maximize)?.cost)
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    parse::{self, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Reindeer Olympics";
+
+    type Input = ReindeerStatsMap;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<ReindeerStatsMap, AocError> {
+        let map = parse_speeds(input)?;
+        if map.is_empty() {
+            return Err(AocError::invalid("There are no reindeer"));
+        }
+        Ok(map)
+    }
+
+    fn part_1(&self, map: &ReindeerStatsMap) -> Result<usize, AocError> {
+        let total_seconds = 2503;
+        let mut max_distance = 0;
+        for stats in map.values() {
+            let current_distance = get_distance_after_n_seconds(total_seconds, stats);
+            if current_distance > max_distance {
+                max_distance = current_distance
+            }
+        }
+        Ok(max_distance)
+    }
+
+    fn part_2(&self, map: &ReindeerStatsMap) -> Result<usize, AocError> {
+        let total_seconds = 2503;
+
+        let mut scoreboard: HashMap<String, usize> = HashMap::new();
+        for step in 1..=total_seconds {
+            let mut distances_map: HashMap<usize, Vec<String>> = HashMap::new();
+            let mut max_distance = 0;
+            for (reindeer_name, stats) in map {
+                let current_distance = get_distance_after_n_seconds(step, stats);
+                distances_map
+                    .entry(current_distance)
+                    .or_default()
+                    .push(reindeer_name.to_string());
+                if current_distance > max_distance {
+                    max_distance = current_distance;
+                }
+            }
+            for reindeer_name in distances_map.remove(&max_distance).unwrap_or_default() {
+                let entry = scoreboard.entry(reindeer_name.to_string()).or_insert(0);
+                *entry += 1;
+            }
+        }
+
+        let mut max_score = 0;
+        for score in scoreboard.into_values() {
+            if score > max_score {
+                max_score = score;
+            }
+        }
+
+        Ok(max_score)
+    }
+}
+
+#[derive(Debug)]
+pub struct ReindeerStats {
+    speed: usize,
+    sprint_duration: usize,
+    rest_duration: usize,
+}
+
+pub type ReindeerStatsMap = HashMap<String, ReindeerStats>;
+
+fn parse_speeds(input: &str) -> Result<ReindeerStatsMap, ParseError> {
+    let mut map: ReindeerStatsMap = HashMap::new();
+    for line in parse::lines(input) {
+        let stats = ReindeerStats {
+            speed: line.field(3)?,
+            sprint_duration: line.field(6)?,
+            rest_duration: line.field(13)?,
+        };
+        if stats.sprint_duration + stats.rest_duration == 0 {
+            return Err(line.error(line.text, "The reindeer neither flies nor rests"));
+        }
+        map.insert(line.word(0)?.to_string(), stats);
+    }
+    Ok(map)
+}
+
+fn get_distance_after_n_seconds(total_seconds: usize, stats: &ReindeerStats) -> usize {
+    let segment_duration = stats.sprint_duration + stats.rest_duration;
+    let completed_segments: usize = total_seconds / segment_duration;
+    let full_segments_duration = segment_duration * completed_segments;
+    let last_segment_duration = total_seconds - full_segments_duration;
+    let total_distance_in_full_sprints = completed_segments * stats.speed * stats.sprint_duration;
+    let distance_in_last_sprint = if last_segment_duration > stats.sprint_duration {
+        stats.speed * stats.sprint_duration
+    } else {
+        stats.speed * last_segment_duration
+    };
+    total_distance_in_full_sprints + distance_in_last_sprint
+}
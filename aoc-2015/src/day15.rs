@@ -0,0 +1,83 @@
+use aoc_core::{
+    parse::{self, Line, ParseError},
+    subsets, AocError, Solution,
+};
+
+#[derive(Debug)]
+pub struct Ingredient {
+    capacity: i64,
+    durability: i64,
+    flavor: i64,
+    texture: i64,
+    calories: i64,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Science for Hungry People";
+
+    type Input = Vec<Ingredient>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Ingredient>, AocError> {
+        Ok(parse::parse_lines(input, parse_ingredient)?)
+    }
+
+    fn part_1(&self, ingredients: &Vec<Ingredient>) -> Result<i64, AocError> {
+        Ok(highest_score(ingredients, None))
+    }
+
+    fn part_2(&self, ingredients: &Vec<Ingredient>) -> Result<i64, AocError> {
+        Ok(highest_score(ingredients, Some(500)))
+    }
+}
+
+fn parse_ingredient(line: Line) -> Result<Ingredient, ParseError> {
+    let [capacity, durability, flavor, texture, calories] = line.integers_n()?;
+    Ok(Ingredient {
+        capacity,
+        durability,
+        flavor,
+        texture,
+        calories,
+    })
+}
+
+/// Best score among the recipes with 100 teaspoons of ingredients, and
+/// exactly `target_calories` if given.
+fn highest_score(ingredients: &[Ingredient], target_calories: Option<i64>) -> i64 {
+    subsets::compositions(100, ingredients.len())
+        .filter_map(|teaspoons| {
+            let (score, calories) = calculate_score(ingredients, &teaspoons);
+            target_calories
+                .is_none_or(|target| target == calories)
+                .then_some(score)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Score and calories of a recipe with `teaspoons` of each ingredient.
+fn calculate_score(ingredients: &[Ingredient], teaspoons: &[u64]) -> (i64, i64) {
+    let mut capacity = 0;
+    let mut durability = 0;
+    let mut flavor = 0;
+    let mut texture = 0;
+    let mut calories = 0;
+    for (ingredient, &count) in ingredients.iter().zip(teaspoons) {
+        let count = count as i64;
+        capacity += count * ingredient.capacity;
+        durability += count * ingredient.durability;
+        flavor += count * ingredient.flavor;
+        texture += count * ingredient.texture;
+        calories += count * ingredient.calories;
+    }
+    if capacity <= 0 || durability <= 0 || flavor <= 0 || texture <= 0 {
+        return (0, calories);
+    }
+    (capacity * durability * flavor * texture, calories)
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    parse::{self, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day16;
+
+type Aunt = HashMap<String, usize>;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Aunt Sue";
+
+    type Input = Vec<Aunt>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Aunt>, AocError> {
+        Ok(parse_aunt_list(input)?)
+    }
+
+    fn part_1(&self, aunts: &Vec<Aunt>) -> Result<usize, AocError> {
+        let target_properties = get_target_properties();
+        let mut matching_aunts: Vec<usize> = vec![];
+        for (aunt_index, aunt) in aunts.iter().enumerate() {
+            let mut matches = true;
+            for (property, value) in aunt {
+                if let Some(target_value) = target_properties.get(property) {
+                    if target_value != value {
+                        matches = false;
+                        break;
+                    }
+                }
+            }
+            if matches {
+                matching_aunts.push(aunt_index + 1);
+            }
+        }
+        matching_aunts
+            .first()
+            .copied()
+            .ok_or_else(|| AocError::runtime("No aunt matches"))
+    }
+
+    fn part_2(&self, aunts: &Vec<Aunt>) -> Result<usize, AocError> {
+        let target_properties = get_target_properties();
+        let mut matching_aunts: Vec<usize> = vec![];
+        for (aunt_index, aunt) in aunts.iter().enumerate() {
+            let mut matches = true;
+            for (property, value) in aunt {
+                if let Some(target_value) = target_properties.get(property) {
+                    match &property[..] {
+                        "cats" | "trees" => {
+                            if value <= target_value {
+                                matches = false;
+                                break;
+                            }
+                        }
+                        "pomeranians" | "goldfish" => {
+                            if value >= target_value {
+                                matches = false;
+                                break;
+                            }
+                        }
+                        _ => {
+                            if target_value != value {
+                                matches = false;
+                                break;
+                            }
+                        }
+                    };
+                }
+            }
+            if matches {
+                matching_aunts.push(aunt_index + 1);
+            }
+        }
+        matching_aunts
+            .first()
+            .copied()
+            .ok_or_else(|| AocError::runtime("No aunt matches"))
+    }
+}
+
+fn parse_aunt_list(input: &str) -> Result<Vec<Aunt>, ParseError> {
+    let mut aunts = vec![];
+    for line in parse::lines(input) {
+        let mut aunt_properties: Aunt = HashMap::new();
+        let line_parts: Vec<&str> = line.words().skip(2).collect();
+        for chunk in line_parts.chunks(2) {
+            let [name, value] = chunk else {
+                return Err(line.error(chunk[0], "Missing property value"));
+            };
+            let property_name = name
+                .strip_suffix(':')
+                .ok_or_else(|| line.error(name, "Missing `:` after property"))?;
+            let property_value = line.parse(value.strip_suffix(',').unwrap_or(value))?;
+            aunt_properties.insert(property_name.to_string(), property_value);
+        }
+        aunts.push(aunt_properties);
+    }
+    Ok(aunts)
+}
+
+fn get_target_properties() -> HashMap<String, usize> {
+    let mut properties = HashMap::new();
+    properties.insert(String::from("children"), 3);
+    properties.insert(String::from("cats"), 7);
+    properties.insert(String::from("samoyeds"), 2);
+    properties.insert(String::from("pomeranians"), 3);
+    properties.insert(String::from("akitas"), 0);
+    properties.insert(String::from("vizslas"), 0);
+    properties.insert(String::from("goldfish"), 5);
+    properties.insert(String::from("trees"), 3);
+    properties.insert(String::from("cars"), 2);
+    properties.insert(String::from("perfumes"), 1);
+    properties
+}
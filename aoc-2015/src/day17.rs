@@ -0,0 +1,29 @@
+use aoc_core::{parse, subsets, AocError, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "No Such Thing as Too Much";
+
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, AocError> {
+        Ok(parse::parse_lines(input, |line| line.parse(line.text))?)
+    }
+
+    fn part_1(&self, containers: &Vec<u64>) -> Result<u64, AocError> {
+        Ok(subsets::count(containers, 150))
+    }
+
+    /// Ways to use as few containers as possible.
+    fn part_2(&self, containers: &Vec<u64>) -> Result<u64, AocError> {
+        Ok(subsets::count_by_size(containers, 150)
+            .into_iter()
+            .find(|&ways| ways > 0)
+            .unwrap_or(0))
+    }
+}
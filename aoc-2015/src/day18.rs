@@ -0,0 +1,62 @@
+use aoc_core::{grid::Grid, AocError, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Like a GIF For Your Yard";
+
+    type Input = Grid<bool>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<bool>, AocError> {
+        let grid = Grid::parse(input, |character| match character {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if grid.is_empty() {
+            return Err(AocError::invalid("The grid is empty"));
+        }
+        Ok(grid)
+    }
+
+    fn part_1(&self, grid: &Grid<bool>) -> Result<usize, AocError> {
+        let mut grid = grid.clone();
+        for _ in 0..100 {
+            grid = calculate_next_grid(&grid);
+        }
+        Ok(count_on_lights(&grid))
+    }
+
+    fn part_2(&self, grid: &Grid<bool>) -> Result<usize, AocError> {
+        let mut grid = grid.clone();
+        let (right, bottom) = (grid.width() - 1, grid.height() - 1);
+        for _ in 0..100 {
+            grid = calculate_next_grid(&grid);
+            for corner in [(0, 0), (right, 0), (0, bottom), (right, bottom)] {
+                grid[corner] = true;
+            }
+        }
+        Ok(count_on_lights(&grid))
+    }
+}
+
+fn count_on_lights(grid: &Grid<bool>) -> usize {
+    grid.values().filter(|&&light| light).count()
+}
+
+fn calculate_next_grid(grid: &Grid<bool>) -> Grid<bool> {
+    Grid::from_fn(grid.width(), grid.height(), |position| {
+        let on_neighbors = grid
+            .neighbours_8(position)
+            .filter(|&neighbor| grid[neighbor])
+            .count();
+        match grid[position] {
+            true => on_neighbors == 2 || on_neighbors == 3,
+            false => on_neighbors == 3,
+        }
+    })
+}
@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{AocError, Solution};
+
+pub struct Day19;
+
+type Transformations = HashMap<String, Vec<String>>;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Medicine for Rudolph";
+
+    type Input = (Transformations, String);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<(Transformations, String), AocError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_1(
+        &self,
+        (transformations, molecule): &(Transformations, String),
+    ) -> Result<usize, AocError> {
+        let mut seen: HashSet<String> = HashSet::new();
+        for (source, possible_transformations) in transformations {
+            for transformation in possible_transformations.iter() {
+                for (index, source_match) in molecule.match_indices(source) {
+                    let mutated_molecule =
+                        transform_string(molecule, index, source_match.len(), transformation);
+                    seen.insert(mutated_molecule);
+                }
+            }
+        }
+        Ok(seen.len())
+    }
+
+    // BFS was too slow here, and greedily undoing replacements gets stuck, so
+    // parse the molecule with the replacements as a grammar instead
+    fn part_2(
+        &self,
+        (transformations, molecule): &(Transformations, String),
+    ) -> Result<usize, AocError> {
+        Grammar::new(transformations)
+            .fewest_steps("e", molecule)
+            .ok_or_else(|| {
+                AocError::runtime("The molecule can't be made from e with these replacements")
+            })
+    }
+}
+
+fn parse_input(input: &str) -> (Transformations, String) {
+    let mut transformations: Transformations = HashMap::new();
+    let mut molecule: String = String::from("");
+    for line in input.lines() {
+        let line_parts: Vec<&str> = line.split(' ').collect();
+        if line_parts.len() == 3 {
+            transformations
+                .entry(line_parts[0].to_string())
+                .or_default()
+                .push(line_parts[2].to_string());
+        }
+        if line_parts.len() == 1 {
+            molecule = String::from(line_parts[0]);
+        }
+    }
+    (transformations, molecule)
+}
+
+fn transform_string(string: &str, index: usize, num_chars: usize, replacement: &str) -> String {
+    let (first_part, rest) = string.split_at(index);
+    first_part
+        .chars()
+        .chain(replacement.chars())
+        .chain(rest.chars().skip(num_chars))
+        .collect()
+}
+
+/// Elements of a molecule, each an uppercase letter followed by lowercase
+/// ones, apart from the electron `e`.
+fn elements(molecule: &str) -> Vec<&str> {
+    let mut elements = vec![];
+    let mut start = 0;
+    for (index, character) in molecule.char_indices().skip(1) {
+        if !character.is_ascii_lowercase() || &molecule[start..index] == "e" {
+            elements.push(&molecule[start..index]);
+            start = index;
+        }
+    }
+    if start < molecule.len() {
+        elements.push(&molecule[start..]);
+    }
+    elements
+}
+
+/// Replacements as a grammar in which each rule makes one or two symbols.
+/// Longer replacements are split up with extra symbols standing for their
+/// rest.
+struct Grammar<'a> {
+    symbols: HashMap<&'a str, usize>,
+    symbol_count: usize,
+    /// `(from, to)` for replacements of a single element by another.
+    units: Vec<(usize, usize)>,
+    /// The symbols making `(left, right)`, with whether that's a replacement
+    /// of its own rather than the rest of one.
+    pairs: HashMap<(usize, usize), Vec<(usize, bool)>>,
+}
+
+impl<'a> Grammar<'a> {
+    fn new(transformations: &'a Transformations) -> Self {
+        let mut grammar = Grammar {
+            symbols: HashMap::new(),
+            symbol_count: 0,
+            units: vec![],
+            pairs: HashMap::new(),
+        };
+        for (source, targets) in transformations {
+            for target in targets {
+                let from = grammar.symbol(source);
+                let target: Vec<usize> = elements(target)
+                    .into_iter()
+                    .map(|element| grammar.symbol(element))
+                    .collect();
+                if let [to] = target[..] {
+                    grammar.units.push((from, to));
+                    continue;
+                }
+                // `A => B C D` becomes `A => B A'` and `A' => C D`
+                let (mut parent, mut is_step) = (from, true);
+                let mut rest = &target[..];
+                while let [left, ref others @ ..] = *rest {
+                    if let [right] = *others {
+                        grammar.pair(parent, is_step, left, right);
+                        break;
+                    }
+                    let next = grammar.new_symbol();
+                    grammar.pair(parent, is_step, left, next);
+                    (parent, is_step, rest) = (next, false, others);
+                }
+            }
+        }
+        grammar
+    }
+
+    fn symbol(&mut self, element: &'a str) -> usize {
+        if let Some(&symbol) = self.symbols.get(element) {
+            return symbol;
+        }
+        let symbol = self.new_symbol();
+        self.symbols.insert(element, symbol);
+        symbol
+    }
+
+    fn new_symbol(&mut self) -> usize {
+        self.symbol_count += 1;
+        self.symbol_count - 1
+    }
+
+    fn pair(&mut self, parent: usize, is_step: bool, left: usize, right: usize) {
+        self.pairs
+            .entry((left, right))
+            .or_default()
+            .push((parent, is_step));
+    }
+
+    /// Also makes every symbol that unit replacements turn into one of
+    /// `made`, at one more step each.
+    fn close_units(&self, made: &mut HashMap<usize, usize>) {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &(from, to) in &self.units {
+                let Some(steps) = made.get(&to).and_then(|steps| steps.checked_add(1)) else {
+                    continue;
+                };
+                if made.get(&from).is_none_or(|&known| steps < known) {
+                    made.insert(from, steps);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    /// Fewest replacements turning `start` into `molecule`, by finding the
+    /// cheapest way to make every run of its elements (CYK).
+    fn fewest_steps(&self, start: &str, molecule: &str) -> Option<usize> {
+        let start = *self.symbols.get(start)?;
+        let elements = elements(molecule);
+        let count = elements.len();
+        if count == 0 {
+            return None;
+        }
+        // made[from][length - 1] has the symbols making that run of elements
+        let mut made: Vec<Vec<HashMap<usize, usize>>> = vec![vec![]; count];
+        for (from, element) in elements.iter().enumerate() {
+            let mut single = HashMap::new();
+            // an element missing from the replacements can't be made at all
+            if let Some(&symbol) = self.symbols.get(element) {
+                single.insert(symbol, 0);
+            }
+            self.close_units(&mut single);
+            made[from].push(single);
+        }
+        for length in 2..=count {
+            for from in 0..=count - length {
+                let mut run: HashMap<usize, usize> = HashMap::new();
+                for split in 1..length {
+                    let left = &made[from][split - 1];
+                    let right = &made[from + split][length - split - 1];
+                    for (&left_symbol, &left_steps) in left {
+                        for (&right_symbol, &right_steps) in right {
+                            let Some(parents) = self.pairs.get(&(left_symbol, right_symbol)) else {
+                                continue;
+                            };
+                            for &(parent, is_step) in parents {
+                                let steps = left_steps
+                                    .checked_add(right_steps)?
+                                    .checked_add(usize::from(is_step))?;
+                                if run.get(&parent).is_none_or(|&known| steps < known) {
+                                    run.insert(parent, steps);
+                                }
+                            }
+                        }
+                    }
+                }
+                self.close_units(&mut run);
+                made[from].push(run);
+            }
+        }
+        made[0][count - 1].get(&start).copied()
+    }
+}
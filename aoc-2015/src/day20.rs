@@ -0,0 +1,48 @@
+use aoc_core::{math, parse, AocError, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Infinite Elves and Infinite Houses";
+
+    type Input = usize;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<usize, AocError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| AocError::invalid("The input is empty"))?;
+        Ok(line.parse(line.text.trim())?)
+    }
+
+    fn part_1(&self, target_presents: &usize) -> Result<usize, AocError> {
+        first_house(*target_presents, 10, math::divisor_sums)
+    }
+
+    fn part_2(&self, target_presents: &usize) -> Result<usize, AocError> {
+        first_house(*target_presents, 11, |limit| {
+            math::divisor_sums_capped(limit, 50)
+        })
+    }
+}
+
+/// Lowest house getting at least `target_presents`, each elf leaving
+/// `presents_per_elf` times its number at every house it visits.
+fn first_house(
+    target_presents: usize,
+    presents_per_elf: usize,
+    divisor_sums: impl Fn(usize) -> Vec<u64>,
+) -> Result<usize, AocError> {
+    // elf n alone leaves enough presents at house n, so no house is past it
+    let limit = target_presents / presents_per_elf + 2;
+    divisor_sums(limit)
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|&(_, &sum)| sum as usize * presents_per_elf >= target_presents)
+        .map(|(house, _)| house)
+        .ok_or_else(|| AocError::runtime(format!("No house gets {} presents", target_presents)))
+}
@@ -0,0 +1,216 @@
+use std::{cmp, collections::HashMap};
+
+use aoc_core::{
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+use itertools::Itertools;
+
+#[derive(Debug, Clone)]
+pub struct Stats {
+    hit_points: i64,
+    damage: i64,
+    armor: i64,
+}
+
+#[derive(Debug, Clone)]
+struct Item {
+    cost: i64,
+    damage_increment: i64,
+    armor_increment: i64,
+}
+
+#[derive(Debug)]
+struct Store {
+    weapons: HashMap<String, Item>,
+    armor: HashMap<String, Item>,
+    rings: HashMap<String, Item>,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "RPG Simulator 20XX";
+
+    type Input = Stats;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Stats, AocError> {
+        parse_boss_stats(input)
+    }
+
+    fn part_1(&self, boss: &Stats) -> Result<usize, AocError> {
+        let player = Stats {
+            hit_points: 100,
+            damage: 0,
+            armor: 0,
+        };
+        let store = get_available_weapons()?;
+        let mut lowest_cost_for_win = 0;
+        for item_set in get_all_item_combinations(&store) {
+            if player_wins_fight(&wear_items(&player, &item_set), boss) {
+                let cost = item_set.iter().map(|item| item.cost as usize).sum();
+                if lowest_cost_for_win == 0 || cost < lowest_cost_for_win {
+                    lowest_cost_for_win = cost;
+                }
+            }
+        }
+        Ok(lowest_cost_for_win)
+    }
+
+    fn part_2(&self, boss: &Stats) -> Result<usize, AocError> {
+        let player = Stats {
+            hit_points: 100,
+            damage: 0,
+            armor: 0,
+        };
+        let store = get_available_weapons()?;
+        let mut highest_cost_for_losing = 0;
+        for item_set in get_all_item_combinations(&store) {
+            if !(player_wins_fight(&wear_items(&player, &item_set), boss)) {
+                let cost = item_set.iter().map(|item| item.cost as usize).sum();
+                if cost > highest_cost_for_losing {
+                    highest_cost_for_losing = cost;
+                }
+            }
+        }
+        Ok(highest_cost_for_losing)
+    }
+}
+
+fn get_available_weapons() -> Result<Store, ParseError> {
+    let input = include_str!("bin/day21/store.txt");
+    let mut weapons: HashMap<String, Item> = HashMap::new();
+    let mut armor: HashMap<String, Item> = HashMap::new();
+    let mut rings: HashMap<String, Item> = HashMap::new();
+    let mut current_section = "";
+    for line in parse::lines(input) {
+        let words: Vec<&str> = line.words().collect();
+        let Some(first) = words.first() else {
+            continue;
+        };
+        if let Some(section) = first.strip_suffix(':') {
+            current_section = section;
+            continue;
+        }
+        let section = match current_section {
+            "Weapons" => &mut weapons,
+            "Armor" => &mut armor,
+            "Rings" => &mut rings,
+            _ => return Err(line.error(line.text, "Item outside of a store section")),
+        };
+        let (name, item) = parse_item(&line, &words)?;
+        section.insert(name, item);
+    }
+    Ok(Store {
+        weapons,
+        armor,
+        rings,
+    })
+}
+
+/// An item's name, which may have spaces, followed by its cost, damage and
+/// armor.
+fn parse_item(line: &Line, words: &[&str]) -> Result<(String, Item), ParseError> {
+    match words {
+        [name @ .., cost, damage, armor] if !name.is_empty() => Ok((
+            name.join(" "),
+            Item {
+                cost: line.parse(cost)?,
+                damage_increment: line.parse(damage)?,
+                armor_increment: line.parse(armor)?,
+            },
+        )),
+        _ => Err(line.error(line.text, "Expected a name, cost, damage and armor")),
+    }
+}
+
+fn parse_boss_stats(input: &str) -> Result<Stats, AocError> {
+    let stats = parse::parse_lines(input, |line| {
+        let [stat] = line.integers_n()?;
+        Ok(stat)
+    })?;
+    match stats[..] {
+        [hit_points, damage, armor] => Ok(Stats {
+            hit_points,
+            damage,
+            armor,
+        }),
+        _ => Err(AocError::invalid(
+            "Expected the boss' hit points, damage and armor",
+        )),
+    }
+}
+
+fn player_wins_fight(player: &Stats, boss: &Stats) -> bool {
+    let mut player = player.clone();
+    let mut boss = boss.clone();
+    let mut turn = 0;
+    loop {
+        if turn % 2 == 0 {
+            let damage: i64 = cmp::max(player.damage - boss.armor, 1);
+            boss.hit_points -= damage;
+        } else {
+            let damage: i64 = cmp::max(boss.damage - player.armor, 1);
+            player.hit_points -= damage;
+        }
+        if boss.hit_points <= 0 {
+            return true;
+        }
+        if player.hit_points <= 0 {
+            return false;
+        }
+        turn += 1;
+    }
+}
+
+fn wear_items(player: &Stats, items: &[&Item]) -> Stats {
+    let mut updated_player = Stats { ..*player };
+    for item in items {
+        updated_player.armor += item.armor_increment;
+        updated_player.damage += item.damage_increment;
+    }
+    updated_player
+}
+
+fn get_all_item_combinations(store: &Store) -> Vec<Vec<&Item>> {
+    let available_weapons = store.weapons.values().map(Some).collect_vec();
+    let available_armor = store.armor.values().map(Some).chain([None]).collect_vec();
+    let available_rings = store
+        .rings
+        .values()
+        .map(|ring| Some(vec![ring]))
+        .chain(
+            store
+                .rings
+                .values()
+                .combinations(2)
+                .map(|rings| Some(vec![rings[0], rings[1]])),
+        )
+        .chain([None])
+        .collect_vec();
+    let mut output = vec![];
+    for weapon in &available_weapons {
+        for armor in &available_armor {
+            for rings in &available_rings {
+                let mut items = vec![];
+                if let Some(weapon) = weapon {
+                    items.push(*weapon);
+                }
+                if let Some(armor) = armor {
+                    items.push(*armor);
+                }
+                if let Some(rings) = rings {
+                    for ring in rings {
+                        items.push(*ring);
+                    }
+                }
+                output.push(items);
+            }
+        }
+    }
+    output
+}
@@ -0,0 +1,477 @@
+use std::cmp;
+
+use aoc_core::{
+    parse,
+    search::{Search, SearchSpace},
+    AocError, Solution,
+};
+use itertools::Itertools;
+use log::debug;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Wizard Simulator 20XX";
+
+    type Input = Player;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Player, AocError> {
+        parse_boss_stats(input)
+    }
+
+    fn part_1(&self, boss: &Player) -> Result<usize, AocError> {
+        find_least_mana_to_win(boss, false)
+    }
+
+    fn part_2(&self, boss: &Player) -> Result<usize, AocError> {
+        find_least_mana_to_win(boss, true)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Spell {
+    const MAGIC_MISSILE_COST: i64 = 53;
+    const DRAIN_COST: i64 = 73;
+    const SHIELD_COST: i64 = 113;
+    const POISON_COST: i64 = 173;
+    const RERCHARGE_COST: i64 = 229;
+
+    const ALL: [Spell; 5] = [
+        Self::MagicMissile,
+        Self::Drain,
+        Self::Shield,
+        Self::Poison,
+        Self::Recharge,
+    ];
+
+    fn get_cost(&self) -> i64 {
+        match self {
+            Self::MagicMissile => Self::MAGIC_MISSILE_COST,
+            Self::Drain => Self::DRAIN_COST,
+            Self::Shield => Self::SHIELD_COST,
+            Self::Poison => Self::POISON_COST,
+            Self::Recharge => Self::RERCHARGE_COST,
+        }
+    }
+
+    fn cast(&self, caster: &mut Player, target: &mut Player) {
+        caster.mana -= self.get_cost();
+        match self {
+            Self::MagicMissile => {
+                debug!("Player casts {:?}, dealing {} damage.", self, 4);
+                target.hit_points -= 4;
+            }
+            Self::Drain => {
+                debug!(
+                    "Player casts {:?}, dealing {} damage and healing {} points.",
+                    self, 2, 2
+                );
+                target.hit_points -= 2;
+                caster.hit_points += 2;
+            }
+            Self::Shield => {
+                let effect = Effect {
+                    effect_type: EffectType::Shielded,
+                    duration: EffectType::Shielded.get_initial_duration(),
+                };
+                debug!("Player casts {:?}, increasing armor by {}.", self, 7);
+                *caster = effect.set(caster);
+                caster.effects.push(effect);
+            }
+            Self::Poison => {
+                debug!("Player casts {:?}", self);
+                let effect = Effect {
+                    effect_type: EffectType::Poisoned,
+                    duration: EffectType::Poisoned.get_initial_duration(),
+                };
+                *target = effect.set(target);
+                target.effects.push(effect);
+            }
+            Self::Recharge => {
+                debug!("Player casts {:?}", self);
+                let effect = Effect {
+                    effect_type: EffectType::Recharging,
+                    duration: EffectType::Recharging.get_initial_duration(),
+                };
+                *caster = effect.set(caster);
+                caster.effects.push(effect);
+            }
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Player {
+    hit_points: i64,
+    damage: i64,
+    armor: i64,
+    mana: i64,
+    effects: Vec<Effect>,
+}
+
+impl Player {
+    fn is_alive(&self) -> bool {
+        self.hit_points > 0
+    }
+
+    fn is_dead(&self) -> bool {
+        self.hit_points <= 0
+    }
+
+    fn can_cast(&self, spell: &Spell) -> bool {
+        spell.get_cost() <= self.mana
+    }
+}
+
+fn parse_boss_stats(input: &str) -> Result<Player, AocError> {
+    let stats = parse::parse_lines(input, |line| {
+        let [stat] = line.integers_n()?;
+        Ok(stat)
+    })?;
+    match stats[..] {
+        [hit_points, damage] => Ok(Player {
+            hit_points,
+            damage,
+            armor: 0,
+            mana: 0,
+            effects: vec![],
+        }),
+        _ => Err(AocError::invalid(
+            "Expected the boss' hit points and damage",
+        )),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Effect {
+    effect_type: EffectType,
+    duration: usize,
+}
+
+impl Effect {
+    const POISON_DAMAGE_PER_TURN: i64 = 3;
+    const RECHARGED_MANA_PER_TURN: i64 = 101;
+    const SHIELD_ARMOR_INCREASE: i64 = 7;
+
+    fn apply(&self, target: &Player) -> Player {
+        let mut target = target.clone();
+        match self.effect_type {
+            EffectType::Poisoned => {
+                debug!(
+                    "Poison deals {} damage; its timer is now {}",
+                    Self::POISON_DAMAGE_PER_TURN,
+                    self.duration - 1
+                );
+                target.hit_points -= Self::POISON_DAMAGE_PER_TURN;
+            }
+            EffectType::Recharging => {
+                debug!(
+                    "Recharge provides {} mana; its timer is now {}",
+                    Self::RECHARGED_MANA_PER_TURN,
+                    self.duration - 1
+                );
+                target.mana += Self::RECHARGED_MANA_PER_TURN;
+            }
+            EffectType::Shielded => {
+                debug!("Shield's timer is now {}", self.duration - 1);
+            }
+        };
+        target
+    }
+
+    fn set(&self, target: &Player) -> Player {
+        let mut target = target.clone();
+        if let EffectType::Shielded = self.effect_type {
+            target.armor += Self::SHIELD_ARMOR_INCREASE
+        };
+        target
+    }
+
+    fn fade(&self, target: &Player) -> Player {
+        let mut target = target.clone();
+        if let EffectType::Shielded = self.effect_type {
+            debug!(
+                "Shield wears off, decreasing armor by {}",
+                Self::SHIELD_ARMOR_INCREASE
+            );
+            target.armor -= Self::SHIELD_ARMOR_INCREASE
+        };
+        target
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum EffectType {
+    Shielded,
+    Poisoned,
+    Recharging,
+}
+
+impl EffectType {
+    const SHIELD_DURATION: usize = 6;
+    const POISON_DURATION: usize = 6;
+    const RECHARGE_DURATION: usize = 5;
+
+    fn get_initial_duration(&self) -> usize {
+        match self {
+            Self::Shielded => Self::SHIELD_DURATION,
+            Self::Poisoned => Self::POISON_DURATION,
+            Self::Recharging => Self::RECHARGE_DURATION,
+        }
+    }
+}
+
+fn apply_effects(player: &Player, boss: &Player) -> (Player, Player) {
+    let mut player = player.clone();
+    let mut boss = boss.clone();
+
+    let mut player_effects = player.effects.clone();
+    for effect in &mut player_effects {
+        player = effect.apply(&player);
+        effect.duration -= 1;
+        if effect.duration == 0 {
+            player = effect.fade(&player)
+        }
+    }
+
+    let mut boss_effects = boss.effects.clone();
+    for effect in &mut boss_effects {
+        boss = effect.apply(&boss);
+        effect.duration -= 1;
+        if effect.duration == 0 {
+            boss = effect.fade(&boss)
+        }
+    }
+
+    player.effects = player_effects
+        .into_iter()
+        .filter(|effect| effect.duration > 0)
+        .collect_vec();
+    boss.effects = boss_effects
+        .into_iter()
+        .filter(|effect| effect.duration > 0)
+        .collect_vec();
+    // player.effects = player
+    //     .effects
+    //     .into_iter()
+    //     .map(|effect| Effect {
+    //         duration: effect.duration - 1,
+    //         ..effect
+    //     })
+    //     .filter(|effect| effect.duration > 0)
+    //     .collect_vec();
+    // boss.effects = boss
+    //     .effects
+    //     .into_iter()
+    //     .map(|effect| Effect {
+    //         duration: effect.duration - 1,
+    //         ..effect
+    //     })
+    //     .filter(|effect| effect.duration > 0)
+    //     .collect_vec();
+
+    (player, boss)
+}
+
+fn execute_player_turn(
+    player: &Player,
+    boss: &Player,
+    spell: &Spell,
+    hard_mode: bool,
+) -> (Player, Player) {
+    let mut player = player.clone();
+    let mut boss = boss.clone();
+
+    if hard_mode {
+        player.hit_points -= 1;
+    }
+    if player.is_dead() {
+        return (player, boss);
+    }
+
+    let updated = apply_effects(&player, &boss);
+    player = updated.0;
+    boss = updated.1;
+
+    if player.is_alive() {
+        // cast spell
+        let effect_already_applied: bool = match spell {
+            Spell::Poison => {
+                let count = boss
+                    .effects
+                    .iter()
+                    .any(|effect| matches!(effect.effect_type, EffectType::Poisoned));
+                debug!(
+                    "Looking for {:?}. effects = {:?}; (match = {})",
+                    spell,
+                    boss.effects.clone(),
+                    count
+                );
+                if count {
+                    debug!(
+                        "Player tried to cast {:?} while the boss was already poisoned!",
+                        spell
+                    );
+                }
+                count
+            }
+            Spell::Shield => {
+                let count = player
+                    .effects
+                    .iter()
+                    .any(|effect| matches!(effect.effect_type, EffectType::Shielded));
+                debug!(
+                    "Looking for {:?}. effects = {:?}; (match = {})",
+                    spell,
+                    player.effects.clone(),
+                    count
+                );
+                if count {
+                    debug!("Player tried to cast {:?} while shielded already!", spell);
+                }
+                count
+            }
+            Spell::Recharge => {
+                let count = player
+                    .effects
+                    .iter()
+                    .any(|effect| matches!(effect.effect_type, EffectType::Recharging));
+                debug!(
+                    "Looking for {:?}. effects = {:?}; (match = {})",
+                    spell,
+                    player.effects.clone(),
+                    count
+                );
+                if count {
+                    debug!("Player tried to cast {:?} while recharging already!", spell);
+                }
+                count
+            }
+            _ => false,
+        };
+        if player.can_cast(spell) && !effect_already_applied {
+            spell.cast(&mut player, &mut boss);
+        } else {
+            debug!("Player dies due to lack of mana / casting double effect.");
+            player.hit_points = 0;
+        }
+    }
+
+    (player, boss)
+}
+fn execute_boss_turn(player: &Player, boss: &Player) -> (Player, Player) {
+    let mut player = player.clone();
+    let mut boss = boss.clone();
+
+    let updated = apply_effects(&player, &boss);
+    player = updated.0;
+    boss = updated.1;
+
+    if boss.is_alive() {
+        let damage = cmp::max(boss.damage - player.armor, 1);
+        debug!("Boss attacks for {} damage.", damage);
+        player.hit_points -= damage;
+    } else {
+        debug!("Boss dies (HP = {})", boss.hit_points);
+    }
+
+    (player, boss)
+}
+
+/// Both fighters between full turns. How they got there doesn't matter, so
+/// fights reached with different spells are only expanded once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Fight {
+    player: Player,
+    boss: Player,
+}
+
+/// Every spell the player can cast and survive the turn with, each move
+/// costing the mana of its spell.
+struct Duel {
+    hard_mode: bool,
+}
+
+impl SearchSpace for Duel {
+    type State = Fight;
+
+    fn neighbours(&self, fight: &Fight) -> impl IntoIterator<Item = Fight> {
+        self.moves(fight).into_iter().map(|(fight, _)| fight)
+    }
+
+    /// The cost is the spell's, which the fight after the turn doesn't keep.
+    fn moves(&self, fight: &Fight) -> impl IntoIterator<Item = (Fight, u64)> {
+        Spell::ALL.into_iter().filter_map(|spell| {
+            let (player, boss) =
+                execute_full_turn(&fight.player, &fight.boss, &spell, self.hard_mode);
+            player
+                .is_alive()
+                .then_some((Fight { player, boss }, spell.get_cost() as u64))
+        })
+    }
+
+    fn is_goal(&self, fight: &Fight) -> bool {
+        fight.boss.is_dead()
+    }
+}
+
+fn find_least_mana_to_win(boss: &Player, hard_mode: bool) -> Result<usize, AocError> {
+    let player = Player {
+        damage: 0,
+        armor: 0,
+        mana: 500,
+        hit_points: 50,
+        effects: vec![],
+    };
+    let start = Fight {
+        player,
+        boss: boss.clone(),
+    };
+    let path = Search::new(&Duel { hard_mode })
+        .dijkstra(start)
+        .ok_or_else(|| AocError::runtime("The boss can't be beaten"))?;
+    debug!(
+        "Player won using {} mana in {} turns",
+        path.cost,
+        path.states.len() - 1
+    );
+    Ok(path.cost as usize)
+}
+
+fn execute_full_turn(
+    player: &Player,
+    boss: &Player,
+    spell: &Spell,
+    hard_mode: bool,
+) -> (Player, Player) {
+    debug!("\n-- Player turn --");
+    debug!(
+        "- Player has {} hit points, {} armor, {} mana",
+        player.hit_points, player.armor, player.mana
+    );
+    debug!("- Boss has {} hit points", boss.hit_points);
+    let (player, boss) = execute_player_turn(player, boss, spell, hard_mode);
+    if player.is_dead() || boss.is_dead() {
+        (player, boss)
+    } else {
+        debug!("\n-- Boss turn --");
+        debug!(
+            "- Player has {} hit points, {} armor, {} mana",
+            player.hit_points, player.armor, player.mana
+        );
+        debug!("- Boss has {} hit points", boss.hit_points);
+        execute_boss_turn(&player, &boss)
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_core::{
+    parse::{self, Line, ParseError},
+    vm::{InstructionSet, Machine, Register},
+    AocError, Solution,
+};
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Hlf(Register),
+    Tpl(Register),
+    Inc(Register),
+    Jmp(i64),
+    Jie(Register, i64),
+    Jio(Register, i64),
+}
+
+impl InstructionSet for Instruction {
+    const REGISTERS: &'static [&'static str] = &["a", "b"];
+
+    fn execute(&self, machine: &mut Machine<Self>) -> i64 {
+        match *self {
+            Instruction::Hlf(register) => machine.set(register, machine.get(register) / 2),
+            Instruction::Tpl(register) => match machine.get(register).checked_mul(3) {
+                Some(value) => machine.set(register, value),
+                None => machine.fail("Tripling a register overflowed"),
+            },
+            Instruction::Inc(register) => match machine.get(register).checked_add(1) {
+                Some(value) => machine.set(register, value),
+                None => machine.fail("Incrementing a register overflowed"),
+            },
+            Instruction::Jmp(offset) => return offset,
+            Instruction::Jie(register, offset) => {
+                if machine.get(register) % 2 == 0 {
+                    return offset;
+                }
+            }
+            Instruction::Jio(register, offset) => {
+                if machine.get(register) == 1 {
+                    return offset;
+                }
+            }
+        }
+        1
+    }
+}
+
+pub type Program = Vec<Instruction>;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Opening the Turing Lock";
+
+    type Input = Program;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Program, AocError> {
+        Ok(parse::parse_lines(input, parse_instruction)?)
+    }
+
+    fn part_1(&self, program: &Program) -> Result<i64, AocError> {
+        register_b_after(Machine::new(program.clone()))
+    }
+
+    fn part_2(&self, program: &Program) -> Result<i64, AocError> {
+        register_b_after(Machine::new(program.clone()).with_register("a", 1))
+    }
+}
+
+/// Instructions to run before giving up on a program that never halts.
+const MAX_STEPS: usize = 10_000_000;
+
+fn register_b_after(mut machine: Machine<Instruction>) -> Result<i64, AocError> {
+    if !machine.run_for(MAX_STEPS) {
+        return Err(AocError::runtime(format!(
+            "The program is still running after {} instructions",
+            MAX_STEPS
+        )));
+    }
+    if let Some(fault) = machine.fault() {
+        return Err(AocError::runtime(fault));
+    }
+    machine
+        .value("b")
+        .ok_or_else(|| AocError::runtime("The machine has no register b"))
+}
+
+fn parse_register(line: &Line, register: &str) -> Result<Register, ParseError> {
+    let register = register.strip_suffix(',').unwrap_or(register);
+    Instruction::register(line, register)
+}
+
+fn parse_instruction(line: Line) -> Result<Instruction, ParseError> {
+    let words: Vec<&str> = line.words().collect();
+    match words[..] {
+        ["hlf", register] => Ok(Instruction::Hlf(parse_register(&line, register)?)),
+        ["tpl", register] => Ok(Instruction::Tpl(parse_register(&line, register)?)),
+        ["inc", register] => Ok(Instruction::Inc(parse_register(&line, register)?)),
+        ["jmp", offset] => Ok(Instruction::Jmp(line.parse(offset)?)),
+        ["jie", register, offset] => Ok(Instruction::Jie(
+            parse_register(&line, register)?,
+            line.parse(offset)?,
+        )),
+        ["jio", register, offset] => Ok(Instruction::Jio(
+            parse_register(&line, register)?,
+            line.parse(offset)?,
+        )),
+        [opcode, ..] => Err(line.error(opcode, "Invalid instruction")),
+        [] => Err(line.error(line.text, "Empty instruction")),
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_core::{parse, subsets, AocError, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "It Hangs in the Balance";
+
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, AocError> {
+        Ok(parse::parse_lines(input, |line| line.parse(line.text))?)
+    }
+
+    fn part_1(&self, weights: &Vec<u64>) -> Result<u64, AocError> {
+        min_entanglement(weights, 3)
+    }
+
+    fn part_2(&self, weights: &Vec<u64>) -> Result<u64, AocError> {
+        min_entanglement(weights, 4)
+    }
+}
+
+/// Quantum entanglement (product of the weights) of the group in front, which
+/// has as few packages as possible, the smallest entanglement among those,
+/// and leaves packages that can be split evenly over the other groups.
+fn min_entanglement(weights: &[u64], groups: usize) -> Result<u64, AocError> {
+    let total: u64 = weights.iter().sum();
+    if !total.is_multiple_of(groups as u64) {
+        return Err(AocError::invalid(format!(
+            "A total weight of {} can't be split in {} groups",
+            total, groups
+        )));
+    }
+    let target = total / groups as u64;
+    for size in 1..=weights.len() {
+        let mut candidates: Vec<(u64, Vec<usize>)> = subsets::of_size(weights, size, target)
+            .into_iter()
+            .map(|group| (group.iter().map(|&index| weights[index]).product(), group))
+            .collect();
+        candidates.sort_unstable();
+        for (entanglement, group) in candidates {
+            let rest: Vec<u64> = (0..weights.len())
+                .filter(|index| !group.contains(index))
+                .map(|index| weights[index])
+                .collect();
+            if subsets::can_partition(&rest, groups - 1) {
+                return Ok(entanglement);
+            }
+        }
+    }
+    Err(AocError::invalid("The packages can't be balanced"))
+}
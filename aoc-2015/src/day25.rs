@@ -0,0 +1,56 @@
+use aoc_core::{math, parse, AocError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Let It Snow";
+
+    type Input = Coordinates;
+    type Output1 = u64;
+    type Output2 = ();
+
+    fn parse(&self, input: &str) -> Result<Coordinates, AocError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"row (\d+), column (\d+)").unwrap();
+        }
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| AocError::invalid("The input is empty"))?;
+        let captures = line.captures(&RE)?;
+        let (row, col): (u64, u64) = (captures.get(1)?, captures.get(2)?);
+        if row == 0 || col == 0 {
+            return Err(line.error(line.text, "Rows and columns start at 1").into());
+        }
+        Ok(Coordinates { row, col })
+    }
+
+    fn part_1(&self, coordinates: &Coordinates) -> Result<u64, AocError> {
+        Ok(code_at(coordinates))
+    }
+
+    fn part_2(&self, _coordinates: &Coordinates) -> Result<(), AocError> {
+        Ok(())
+    }
+}
+
+pub struct Coordinates {
+    row: u64,
+    col: u64,
+}
+
+const FIRST_CODE: u64 = 20151125;
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+
+/// Codes are filled in diagonal by diagonal, each one being the previous one
+/// times `MULTIPLIER`, so the code at `coordinates` is the first one times
+/// `MULTIPLIER` to the number of codes before it.
+fn code_at(coordinates: &Coordinates) -> u64 {
+    let diagonal = coordinates.row + coordinates.col - 1;
+    let position = diagonal * (diagonal - 1) / 2 + coordinates.col;
+    FIRST_CODE * math::mod_pow(MULTIPLIER, position - 1, MODULUS) % MODULUS
+}
@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    geometry::{Heading, Point},
+    parse::{self, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "No Time for a Taxicab";
+
+    type Input = Vec<RelativeDirection>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<RelativeDirection>, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, directions: &Vec<RelativeDirection>) -> Result<i64, AocError> {
+        let mut context = Context {
+            facing: Heading::North,
+            coordinates: Point::ORIGIN,
+        };
+        for direction in directions {
+            context = follow_direction(&context, direction);
+        }
+        Ok(context.coordinates.manhattan(Point::ORIGIN))
+    }
+
+    fn part_2(&self, directions: &Vec<RelativeDirection>) -> Result<i64, AocError> {
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut context = Context {
+            facing: Heading::North,
+            coordinates: Point::ORIGIN,
+        };
+        visited.insert(context.coordinates);
+        for direction in directions {
+            let end_context = follow_direction(&context, direction);
+            for step in context.coordinates.line_to(end_context.coordinates).skip(1) {
+                if visited.contains(&step) {
+                    return Ok(step.manhattan(Point::ORIGIN));
+                }
+                visited.insert(step);
+            }
+            context = end_context;
+        }
+        Ok(0)
+    }
+}
+
+#[derive(Debug)]
+pub enum RelativeDirection {
+    Left(i64),
+    Right(i64),
+}
+
+struct Context {
+    facing: Heading,
+    coordinates: Point,
+}
+
+fn parse_input(input: &str) -> Result<Vec<RelativeDirection>, ParseError> {
+    let mut directions = vec![];
+    for line in parse::lines(input) {
+        for relative_direction in line.text.trim().split(", ") {
+            let mut characters = relative_direction.chars();
+            let turn = characters.next();
+            let magnitude = line.parse(characters.as_str())?;
+            directions.push(match turn {
+                Some('R') => RelativeDirection::Right(magnitude),
+                Some('L') => RelativeDirection::Left(magnitude),
+                _ => return Err(line.error(relative_direction, "Invalid direction")),
+            });
+        }
+    }
+    Ok(directions)
+}
+
+fn follow_direction(context: &Context, direction: &RelativeDirection) -> Context {
+    let (facing, magnitude) = match *direction {
+        RelativeDirection::Left(magnitude) => (context.facing.turn_left(), magnitude),
+        RelativeDirection::Right(magnitude) => (context.facing.turn_right(), magnitude),
+    };
+    Context {
+        facing,
+        coordinates: context.coordinates + facing.vector() * magnitude,
+    }
+}
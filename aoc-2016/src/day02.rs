@@ -0,0 +1,71 @@
+use aoc_core::{
+    geometry::{Heading, Point},
+    grid::Grid,
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Bathroom Security";
+
+    type Input = Vec<Vec<Heading>>;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<Heading>>, AocError> {
+        Ok(parse::parse_lines(input, parse_instructions)?)
+    }
+
+    fn part_1(&self, instructions_set_list: &Vec<Vec<Heading>>) -> Result<String, AocError> {
+        let keypad = parse_keypad("123\n456\n789");
+        Ok(find_code(&keypad, Point::new(1, 1), instructions_set_list))
+    }
+
+    fn part_2(&self, instructions_set_list: &Vec<Vec<Heading>>) -> Result<String, AocError> {
+        let keypad = parse_keypad("##1##\n#234#\n56789\n#ABC#\n##D##");
+        Ok(find_code(&keypad, Point::new(0, 2), instructions_set_list))
+    }
+}
+
+/// Keys, with `#` where the keypad has no key.
+type Keypad = Grid<char>;
+
+fn parse_keypad(layout: &str) -> Keypad {
+    Grid::parse(layout, Some).expect("The keypad layouts are valid grids")
+}
+
+fn parse_instructions(line: Line) -> Result<Vec<Heading>, ParseError> {
+    line.text
+        .char_indices()
+        .map(|(offset, character)| {
+            Heading::try_from(character).map_err(|error| {
+                line.error(&line.text[offset..offset + character.len_utf8()], error)
+            })
+        })
+        .collect()
+}
+
+fn key(keypad: &Keypad, position: Point) -> Option<char> {
+    let key = *keypad.get(position.position()?)?;
+    (key != '#').then_some(key)
+}
+
+fn find_code(keypad: &Keypad, start: Point, instructions_set_list: &[Vec<Heading>]) -> String {
+    let mut position = start;
+    let mut output: Vec<char> = vec![];
+
+    for instruction_set in instructions_set_list {
+        for heading in instruction_set {
+            let next = position + heading.vector();
+            if key(keypad, next).is_some() {
+                position = next;
+            }
+        }
+        output.push(key(keypad, position).expect("Moves never leave the keypad"));
+    }
+    output.iter().collect()
+}
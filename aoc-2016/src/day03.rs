@@ -0,0 +1,64 @@
+use aoc_core::{
+    parse::{self, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Squares With Three Sides";
+
+    type Input = Vec<Triangle>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Triangle>, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, triangles: &Vec<Triangle>) -> Result<usize, AocError> {
+        Ok(count_possible_triangles(triangles))
+    }
+
+    fn part_2(&self, triangles: &Vec<Triangle>) -> Result<usize, AocError> {
+        if !triangles.len().is_multiple_of(3) {
+            return Err(AocError::invalid(format!(
+                "Columns list triangles 3 rows at a time, but there are {} rows",
+                triangles.len()
+            )));
+        }
+        let triangles = shift_triangles(triangles);
+        Ok(count_possible_triangles(&triangles))
+    }
+}
+
+pub type Triangle = [usize; 3];
+
+fn parse_input(input: &str) -> Result<Vec<Triangle>, ParseError> {
+    parse::parse_lines(input, |line| line.integers_n())
+}
+
+fn count_possible_triangles(triangles: &[Triangle]) -> usize {
+    triangles
+        .iter()
+        .filter(|[a, b, c]| a + b > *c && b + c > *a && a + c > *b)
+        .count()
+}
+
+fn shift_triangles(input: &[Triangle]) -> Vec<Triangle> {
+    input
+        .chunks(3)
+        .flat_map(|triangles| {
+            let t0 = triangles[0];
+            let t1 = triangles[1];
+            let t2 = triangles[2];
+            [
+                [t0[0], t1[0], t2[0]],
+                [t0[1], t1[1], t2[1]],
+                [t0[2], t1[2], t2[2]],
+            ]
+        })
+        .collect()
+}
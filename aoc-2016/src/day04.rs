@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Security Through Obscurity";
+
+    type Input = Vec<(Vec<char>, usize)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Vec<char>, usize)>, AocError> {
+        Ok(parse::parse_lines(input, parse_line)?)
+    }
+
+    fn part_1(&self, rooms: &Vec<(Vec<char>, usize)>) -> Result<usize, AocError> {
+        let mut output = 0;
+        for (_, sector_id) in rooms {
+            output += sector_id;
+        }
+        Ok(output)
+    }
+
+    fn part_2(&self, rooms: &Vec<(Vec<char>, usize)>) -> Result<usize, AocError> {
+        for (line, sector_id) in rooms {
+            if *sector_id == 0 {
+                continue;
+            }
+            let decoded_room: String = line
+                .iter()
+                .map(|character| shift_character(character, *sector_id))
+                .collect();
+            if decoded_room == "northpole object storage" {
+                return Ok(*sector_id);
+            }
+        }
+        Ok(0)
+    }
+}
+
+fn shift_character(character: &char, sector_id: usize) -> char {
+    match character {
+        '-' => ' ',
+        _ => {
+            ((((*character as usize) - ('a' as usize) + sector_id) % 26) + 'a' as usize) as u8
+                as char
+        }
+    }
+}
+
+fn split_sector_and_checksum(line: &Line, input: &str) -> Result<(usize, [char; 5]), ParseError> {
+    let (sector_id, checksum) = input
+        .split_once('[')
+        .ok_or_else(|| line.error(input, "Missing checksum"))?;
+    let checksum = checksum
+        .strip_suffix(']')
+        .ok_or_else(|| line.error(checksum, "Missing `]` after the checksum"))?;
+    let checksum = checksum
+        .chars()
+        .collect::<Vec<char>>()
+        .try_into()
+        .map_err(|_| line.error(checksum, "The checksum has 5 letters"))?;
+    Ok((line.parse(sector_id)?, checksum))
+}
+
+fn parse_line(line: Line) -> Result<(Vec<char>, usize), ParseError> {
+    let (name, sector_and_checksum) = line
+        .text
+        .rsplit_once('-')
+        .ok_or_else(|| line.error(line.text, "Missing sector id"))?;
+    if let Some(offset) =
+        name.find(|character: char| character != '-' && !character.is_ascii_lowercase())
+    {
+        return Err(line.error(
+            &name[offset..],
+            "Room names are lowercase letters and dashes",
+        ));
+    }
+    let name: Vec<char> = name.chars().collect();
+    let mut counter: HashMap<char, usize> = HashMap::new();
+    name.iter().for_each(|character| {
+        *counter.entry(*character).or_default() += 1;
+    });
+    let (sector_id, checksum) = split_sector_and_checksum(&line, sector_and_checksum)?;
+    if is_valid_checksum(&counter, checksum) {
+        return Ok((name, sector_id));
+    }
+    Ok((name, 0))
+}
+
+fn is_valid_checksum(counter: &HashMap<char, usize>, checksum: [char; 5]) -> bool {
+    let mut last_count: usize = 0;
+    let mut last_char = 0 as char;
+    for character in checksum {
+        if let Some(character_count) = counter.get(&character) {
+            if last_count == 0
+                || *character_count < last_count
+                || (*character_count == last_count && last_char < character)
+            {
+                last_count = *character_count;
+                last_char = character;
+                continue;
+            }
+            return false;
+        } else {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,47 @@
+use aoc_core::{
+    md5::{nibble, HashSearch},
+    AocError, Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "How About a Nice Game of Chess?";
+
+    type Input = String;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> Result<String, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(&self, door: &String) -> Result<String, AocError> {
+        Ok(HashSearch::new(door, 5)
+            .matches()
+            .take(8)
+            .map(|(_, digest)| hex_digit(nibble(&digest, 5)))
+            .collect())
+    }
+
+    fn part_2(&self, door: &String) -> Result<String, AocError> {
+        let mut password: [Option<char>; 8] = [None; 8];
+        let mut matches = HashSearch::new(door, 5).matches();
+        while password.contains(&None) {
+            let (_, digest) = matches
+                .next()
+                .expect("The search never ends without a match");
+            let position = nibble(&digest, 5) as usize;
+            if position < 8 && password[position].is_none() {
+                password[position] = Some(hex_digit(nibble(&digest, 6)));
+            }
+        }
+        Ok(password.iter().flatten().collect())
+    }
+}
+
+fn hex_digit(nibble: u8) -> char {
+    char::from_digit(nibble.into(), 16).unwrap()
+}
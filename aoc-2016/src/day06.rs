@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use aoc_core::{AocError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Signals and Noise";
+
+    type Input = Vec<String>;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part_1(&self, lines: &Vec<String>) -> Result<String, AocError> {
+        let counters = count_characters(lines);
+
+        let mut output: Vec<char> = vec![];
+        for counter in counters.iter() {
+            let mut most_common = ' ';
+            let mut most_common_count: usize = 0;
+            for (character, character_count) in counter {
+                if character_count > &most_common_count {
+                    most_common = *character;
+                    most_common_count = *character_count;
+                }
+            }
+            output.push(most_common);
+        }
+
+        Ok(output.iter().collect())
+    }
+
+    fn part_2(&self, lines: &Vec<String>) -> Result<String, AocError> {
+        let counters = count_characters(lines);
+
+        let mut output: Vec<char> = vec![];
+        for counter in counters.iter() {
+            let mut least_common = ' ';
+            let mut least_common_count: usize = 0;
+            for (character, character_count) in counter {
+                if least_common_count == 0 || character_count < &least_common_count {
+                    least_common = *character;
+                    least_common_count = *character_count;
+                }
+            }
+            output.push(least_common);
+        }
+
+        Ok(output.iter().collect())
+    }
+}
+
+/// How many times each character appears at each position of the messages.
+fn count_characters(lines: &[String]) -> Vec<HashMap<char, usize>> {
+    let width = lines
+        .iter()
+        .map(|line| line.len())
+        .max()
+        .unwrap_or_default();
+    let mut counters: Vec<HashMap<char, usize>> = vec![HashMap::new(); width];
+    for line in lines {
+        line.char_indices().for_each(|(index, character)| {
+            *counters[index].entry(character).or_default() += 1;
+        })
+    }
+    counters
+}
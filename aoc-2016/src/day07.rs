@@ -0,0 +1,112 @@
+use aoc_core::{AocError, Solution};
+use fancy_regex::Regex;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Internet Protocol Version 7";
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part_1(&self, addresses: &Vec<String>) -> Result<usize, AocError> {
+        count_tls_support(addresses)
+    }
+
+    fn part_2(&self, addresses: &Vec<String>) -> Result<usize, AocError> {
+        count_ssl_support(addresses)
+    }
+}
+
+/// The regexes backtrack, which `fancy_regex` gives up on after too many
+/// steps.
+fn regex_error(error: fancy_regex::Error) -> AocError {
+    AocError::runtime(format!("Regex failed: {}", error))
+}
+
+fn count_tls_support(addresses: &[String]) -> Result<usize, AocError> {
+    let abba_regex = Regex::new(r"(?:([a-z])(((?!\1)(?:[a-z])))\2\1)").unwrap();
+    let hypernet_regex = Regex::new(r"\[(.*?)\]").unwrap();
+    let mut support_tls = 0;
+    for line in addresses {
+        if !abba_regex.is_match(line).map_err(regex_error)? {
+            continue;
+        }
+        let mut hypernet_has_abba = false;
+        for hypernet in hypernet_regex.find_iter(line) {
+            let hypernet = hypernet.map_err(regex_error)?;
+            if abba_regex
+                .is_match(hypernet.as_str())
+                .map_err(regex_error)?
+            {
+                hypernet_has_abba = true;
+            }
+        }
+        if !hypernet_has_abba {
+            support_tls += 1;
+        }
+    }
+    Ok(support_tls)
+}
+
+fn count_ssl_support(addresses: &[String]) -> Result<usize, AocError> {
+    let hypernet_regex = Regex::new(r"\[(.*?)\]").unwrap();
+    let mut support_tls = 0;
+    for line in addresses {
+        let matched_abas = line.chars().collect::<Vec<char>>();
+        let matched_abas = matched_abas
+            .windows(3)
+            .fold((false, vec![]), |(in_hypernet, mut output), triplet| {
+                if in_hypernet {
+                    if triplet[0] == ']' || triplet[1] == ']' || triplet[2] == ']' {
+                        return (false, output);
+                    }
+                    return (in_hypernet, output);
+                }
+                if triplet[0] == '[' || triplet[1] == '[' || triplet[2] == '[' {
+                    return (true, output);
+                }
+                if triplet[0] == triplet[2]
+                    && triplet[0] != triplet[1]
+                    && triplet[0] != ']'
+                    && triplet[0] != '['
+                    && triplet[1] != ']'
+                    && triplet[2] != '['
+                {
+                    output.push(triplet.iter().collect::<String>())
+                }
+                (in_hypernet, output)
+            })
+            .1;
+        let hypernets = hypernet_regex
+            .find_iter(line)
+            .map(|m| m.map(|m| m.as_str()))
+            .collect::<Result<Vec<&str>, _>>()
+            .map_err(regex_error)?;
+        let mut contains = false;
+        for aba in matched_abas {
+            let characters = aba.chars().collect::<Vec<char>>();
+            let bab: String = [characters[1], characters[0], characters[1]]
+                .iter()
+                .collect();
+            for hypernet in &hypernets {
+                if hypernet.contains(&bab) {
+                    support_tls += 1;
+                    contains = true;
+                    break;
+                }
+            }
+            if contains {
+                break;
+            }
+        }
+    }
+    Ok(support_tls)
+}
@@ -0,0 +1,113 @@
+use aoc_core::{
+    grid::Grid,
+    ocr,
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+
+/// Size of the screen.
+const WIDTH: usize = 50;
+const HEIGHT: usize = 6;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Two-Factor Authentication";
+
+    type Input = Vec<Instruction>;
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, AocError> {
+        Ok(parse::parse_lines(input, parse_instruction)?)
+    }
+
+    fn part_1(&self, instructions: &Vec<Instruction>) -> Result<usize, AocError> {
+        let screen = apply_instructions(instructions, WIDTH, HEIGHT);
+        Ok(screen.values().filter(|&&light| light).count())
+    }
+
+    fn part_2(&self, instructions: &Vec<Instruction>) -> Result<String, AocError> {
+        let screen = apply_instructions(instructions, WIDTH, HEIGHT);
+        let rows: Vec<&[bool]> = screen.rows().collect();
+        ocr::read(&rows).map_err(|error| {
+            let drawing = screen.render(|&light| if light { '#' } else { ' ' });
+            AocError::runtime(format!("{}\n{}", error, drawing))
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Rect(u8, u8),
+    RotateRow(u8, u8),
+    RotateCol(u8, u8),
+}
+
+/// Parses `text` as a number below `limit`, the size of the screen along
+/// that axis.
+fn parse_index(line: &Line, text: &str, limit: usize, name: &str) -> Result<u8, ParseError> {
+    let index: u8 = line.parse(text)?;
+    if index as usize >= limit {
+        return Err(line.error(text, format!("The screen only has {} {}s", limit, name)));
+    }
+    Ok(index)
+}
+
+fn parse_instruction(line: Line) -> Result<Instruction, ParseError> {
+    let words: Vec<&str> = line.words().collect();
+    match words[..] {
+        ["rect", size] => {
+            let (width, height) = size
+                .split_once('x')
+                .ok_or_else(|| line.error(size, "Expected WxH"))?;
+            let width: u8 = line.parse(width)?;
+            let height: u8 = line.parse(height)?;
+            if width as usize > WIDTH || height as usize > HEIGHT {
+                return Err(line.error(size, "The rectangle doesn't fit on the screen"));
+            }
+            Ok(Instruction::Rect(width, height))
+        }
+        ["rotate", "row", target, "by", shift] => {
+            let row = target
+                .strip_prefix("y=")
+                .ok_or_else(|| line.error(target, "Expected y=ROW"))?;
+            Ok(Instruction::RotateRow(
+                parse_index(&line, row, HEIGHT, "row")?,
+                line.parse(shift)?,
+            ))
+        }
+        ["rotate", "column", target, "by", shift] => {
+            let col = target
+                .strip_prefix("x=")
+                .ok_or_else(|| line.error(target, "Expected x=COLUMN"))?;
+            Ok(Instruction::RotateCol(
+                parse_index(&line, col, WIDTH, "column")?,
+                line.parse(shift)?,
+            ))
+        }
+        _ => Err(line.error(line.text, "Unknown instruction")),
+    }
+}
+
+fn apply_instruction(screen: &mut Grid<bool>, instruction: &Instruction) {
+    match *instruction {
+        Instruction::Rect(width, height) => {
+            for position in screen.rect((0, 0), width as usize, height as usize) {
+                screen[position] = true;
+            }
+        }
+        Instruction::RotateRow(row, shift) => screen.rotate_row(row as usize, shift as usize),
+        Instruction::RotateCol(col, shift) => screen.rotate_column(col as usize, shift as usize),
+    }
+}
+
+fn apply_instructions(instructions: &[Instruction], width: usize, height: usize) -> Grid<bool> {
+    let mut screen = Grid::new(width, height, false);
+    for instruction in instructions {
+        apply_instruction(&mut screen, instruction)
+    }
+    screen
+}
@@ -1,3 +0,0 @@
-fn main() {
-    println!("AOC 2016");
-}
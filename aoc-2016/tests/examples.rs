@@ -0,0 +1,9 @@
+use std::path::Path;
+
+#[test]
+fn test_examples() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    if let Err(failures) = aoc_core::examples::check(&dir, aoc_2016::PUZZLES) {
+        panic!("Failed examples:\n{}", failures.join("\n"));
+    }
+}
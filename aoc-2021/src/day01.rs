@@ -0,0 +1,34 @@
+use aoc_core::{parse, AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, AocError> {
+        Ok(parse::parse_lines(input, |line| line.parse(line.text))?)
+    }
+
+    fn part_1(&self, depths: &Vec<usize>) -> Result<usize, AocError> {
+        Ok(depths
+            .windows(2)
+            .filter(|depths| depths[1] > depths[0])
+            .count())
+    }
+
+    fn part_2(&self, depths: &Vec<usize>) -> Result<usize, AocError> {
+        Ok(depths
+            .windows(3)
+            .map(|depths| depths.iter().sum::<usize>())
+            .collect::<Vec<usize>>()
+            .windows(2)
+            .filter(|sums| sums[1] > sums[0])
+            .count())
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_core::{
+    geometry::{Point, Vector},
+    parse, AocError, Solution,
+};
+
+pub struct Day02;
+
+pub enum Instruction {
+    Down(i64),
+    Up(i64),
+    Forward(i64),
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = &'static str;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let (direction, magnitude) = string.split_once(' ').ok_or("Missing magnitude")?;
+        let magnitude = magnitude.parse::<i64>().map_err(|_| "Invalid magnitude")?;
+
+        match direction {
+            "forward" => Ok(Self::Forward(magnitude)),
+            "down" => Ok(Self::Down(magnitude)),
+            "up" => Ok(Self::Up(magnitude)),
+            _ => Err("Unknown instruction"),
+        }
+    }
+}
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    type Input = Vec<Instruction>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, AocError> {
+        Ok(parse::parse_lines(input, |line| {
+            Instruction::try_from(line.text).map_err(|error| line.error(line.text, error))
+        })?)
+    }
+
+    /// `x` is the horizontal position and `y` the depth, which grows
+    /// downwards.
+    fn part_1(&self, instructions: &Vec<Instruction>) -> Result<i64, AocError> {
+        let mut position = Point::ORIGIN;
+        for instruction in instructions {
+            position += match *instruction {
+                Instruction::Forward(magnitude) => Vector::new(magnitude, 0),
+                Instruction::Down(magnitude) => Vector::new(0, magnitude),
+                Instruction::Up(magnitude) => Vector::new(0, -magnitude),
+            };
+        }
+        Ok(position.x * position.y)
+    }
+
+    fn part_2(&self, instructions: &Vec<Instruction>) -> Result<i64, AocError> {
+        let mut position = Point::ORIGIN;
+        let mut aim = 0;
+        for instruction in instructions {
+            match *instruction {
+                Instruction::Forward(magnitude) => {
+                    position += Vector::new(1, aim) * magnitude;
+                }
+                Instruction::Down(magnitude) => aim += magnitude,
+                Instruction::Up(magnitude) => aim -= magnitude,
+            }
+        }
+        Ok(position.x * position.y)
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_core::{parse, AocError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        let mut width = None;
+        let report = parse::parse_lines(input, |line| {
+            if let Some(offset) = line.text.find(|bit| bit != '0' && bit != '1') {
+                return Err(line.error(&line.text[offset..], "Expected a binary digit"));
+            }
+            let expected = *width.get_or_insert(line.text.len());
+            if line.text.len() != expected || !(1..usize::BITS as usize).contains(&expected) {
+                return Err(line.error(
+                    line.text,
+                    format!("Expected {} bits like the first number", expected),
+                ));
+            }
+            Ok(line.text.to_string())
+        })?;
+        if report.is_empty() {
+            return Err(AocError::invalid("The report is empty"));
+        }
+        Ok(report)
+    }
+
+    fn part_1(&self, report: &Vec<String>) -> Result<usize, AocError> {
+        let numbers = as_strs(report);
+        let binary_counts = get_most_common_bits(&numbers).iter().collect::<String>();
+        let reverse_binary_counts = binary_counts
+            .chars()
+            .map(|x| if x == '1' { '0' } else { '1' })
+            .collect::<String>();
+        let gamma_rate = from_binary(&binary_counts);
+        let epsilon_rate = from_binary(&reverse_binary_counts);
+        Ok(epsilon_rate * gamma_rate)
+    }
+
+    fn part_2(&self, report: &Vec<String>) -> Result<usize, AocError> {
+        let mut numbers = as_strs(report);
+        let mut oxygen_rating = 0;
+        for index in 0..numbers[0].len() {
+            let most_common_bits = get_most_common_bits(&numbers);
+            let most_common_bit_at_index = most_common_bits[index];
+            let new_numbers = numbers
+                .iter()
+                .filter(|number| number.chars().nth(index) == Some(most_common_bit_at_index))
+                .cloned()
+                .collect();
+            numbers = new_numbers;
+            if numbers.len() == 1 {
+                oxygen_rating = from_binary(numbers[0]);
+                break;
+            }
+        }
+
+        let mut numbers = as_strs(report);
+        let mut co_scrubber_rating = 0;
+        for index in 0..numbers[0].len() {
+            let most_common_bit_at_index = get_most_common_bits(&numbers)[index];
+            let least_common_bit_at_index = if most_common_bit_at_index == '1' {
+                '0'
+            } else {
+                '1'
+            };
+            numbers = numbers
+                .iter()
+                .filter(|number| number.chars().nth(index) == Some(least_common_bit_at_index))
+                .cloned()
+                .collect();
+            if numbers.len() == 1 {
+                co_scrubber_rating = from_binary(numbers[0]);
+                break;
+            }
+        }
+
+        Ok(oxygen_rating * co_scrubber_rating)
+    }
+}
+
+/// Value of a number from the report, which parsing checked is binary and
+/// fits a `usize`.
+fn from_binary(number: &str) -> usize {
+    usize::from_str_radix(number, 2).expect("The report only has binary numbers")
+}
+
+fn as_strs(report: &[String]) -> Vec<&str> {
+    report.iter().map(String::as_str).collect()
+}
+
+fn get_most_common_bits(lines: &[&str]) -> Vec<char> {
+    let (one_counts, total_lines) = lines.iter().fold(
+        (vec![], 0),
+        |(mut one_counts, total): (Vec<usize>, usize), line: &&str| {
+            line.char_indices().for_each(|(index, character)| {
+                if index >= one_counts.len() {
+                    one_counts.push(0);
+                }
+                if character == '1' {
+                    one_counts[index] += 1;
+                }
+            });
+            (one_counts, total + 1)
+        },
+    );
+    let half = if total_lines % 2 == 0 {
+        total_lines / 2
+    } else {
+        (total_lines / 2) + 1
+    };
+    one_counts
+        .iter()
+        .map(|ones| if *ones >= half { '1' } else { '0' })
+        .collect()
+}
@@ -0,0 +1,140 @@
+use std::{collections::HashSet, fmt};
+
+use aoc_core::{
+    parse::{self, ParseError},
+    AocError, Solution,
+};
+use itertools::Itertools;
+
+pub struct Day04;
+
+#[derive(Clone)]
+pub struct Board(Vec<Vec<isize>>);
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let display = self
+            .0
+            .iter()
+            .map(|line| line.iter().map(|n| format!("{: >2}", n)).join(" "))
+            .join("\n");
+        write!(f, "{}", display)
+    }
+}
+
+impl Board {
+    fn check_won(&self, row: usize, col: usize) -> bool {
+        let board = &self.0;
+
+        let complete_row = board[row].iter().all(|n| *n == -1);
+        let complete_col = board.iter().all(|line| line[col] == -1);
+        complete_row || complete_col
+    }
+
+    fn mark_number(&mut self, target_number: isize) -> Option<(usize, usize)> {
+        let board = &mut self.0;
+        for (row, line) in board.iter_mut().enumerate() {
+            for (col, n) in line.iter_mut().enumerate() {
+                if *n == target_number {
+                    *n = -1;
+                    return Some((row, col));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Board>, Vec<isize>), ParseError> {
+    let sections = parse::sections(input);
+    let Some(([numbers], boards)) = sections
+        .split_first()
+        .map(|(first, rest)| (&first[..], rest))
+    else {
+        return Err(ParseError {
+            line: 1,
+            column: 1,
+            text: input.lines().next().unwrap_or_default().to_string(),
+            message: "Expected the drawn numbers alone on the first line".to_string(),
+        });
+    };
+    let numbers = numbers.integers()?;
+
+    let boards = boards
+        .iter()
+        .map(|section| {
+            let rows = section
+                .iter()
+                .map(|line| line.integers())
+                .collect::<Result<_, _>>()?;
+            Ok(Board(rows))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((boards, numbers))
+}
+
+fn get_score(board: &Board) -> usize {
+    let Board(board) = board;
+    board
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter(|n| **n != -1)
+                .map(|n| *n as usize)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    type Input = (Vec<Board>, Vec<isize>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<(Vec<Board>, Vec<isize>), AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, (boards, numbers): &(Vec<Board>, Vec<isize>)) -> Result<usize, AocError> {
+        let mut boards = boards.clone();
+
+        for &number in numbers {
+            for board in &mut boards {
+                if let Some((row, col)) = board.mark_number(number) {
+                    if board.check_won(row, col) {
+                        return Ok(get_score(board) * number as usize);
+                    }
+                }
+            }
+        }
+        Ok(0)
+    }
+
+    fn part_2(&self, (boards, numbers): &(Vec<Board>, Vec<isize>)) -> Result<usize, AocError> {
+        let mut boards = boards.clone();
+        let number_of_boards = boards.len();
+        let mut won = HashSet::<usize>::new();
+
+        for &number in numbers {
+            for (board_number, board) in boards.iter_mut().enumerate() {
+                if won.contains(&board_number) {
+                    continue;
+                }
+                if let Some((row, col)) = board.mark_number(number) {
+                    if board.check_won(row, col) {
+                        won.insert(board_number);
+                        if won.len() == number_of_boards {
+                            return Ok(get_score(board) * number as usize);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(0)
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_core::{geometry::Point, grid::Grid, parse, AocError, Solution};
+
+pub struct Day05;
+
+#[derive(Debug)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl TryFrom<&str> for Line {
+    type Error = String;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let parts = input.split(' ').collect::<Vec<_>>();
+        if parts.len() != 3 {
+            return Err(format!("Unable to parse {} as line.", input));
+        }
+        let line = Self {
+            start: parts[0].parse()?,
+            end: parts[2].parse()?,
+        };
+        if line.start.position().is_none() || line.end.position().is_none() {
+            return Err(format!("Negative coordinate in {}", input));
+        }
+        Ok(line)
+    }
+}
+
+impl Line {
+    fn is_diagonal(&self) -> bool {
+        self.start.x != self.end.x && self.start.y != self.end.y
+    }
+}
+
+fn count_overlaps<'a>(lines: impl Iterator<Item = &'a Line> + Clone) -> usize {
+    let corner = lines.clone().fold(Point::ORIGIN, |corner, line| {
+        Point::new(
+            corner.x.max(line.start.x).max(line.end.x),
+            corner.y.max(line.start.y).max(line.end.y),
+        )
+    });
+
+    let mut sea_map = Grid::new(corner.x as usize + 1, corner.y as usize + 1, 0);
+    for line in lines {
+        for point in line.start.line_to(line.end) {
+            sea_map[point
+                .position()
+                .expect("Parsing rejects negative coordinates")] += 1;
+        }
+    }
+    sea_map.values().filter(|&&vents| vents > 1).count()
+}
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    type Input = Vec<Line>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Line>, AocError> {
+        Ok(parse::parse_lines(input, |line| {
+            Line::try_from(line.text).map_err(|error| line.error(line.text, error))
+        })?)
+    }
+
+    fn part_1(&self, lines: &Vec<Line>) -> Result<usize, AocError> {
+        Ok(count_overlaps(
+            lines.iter().filter(|line| !line.is_diagonal()),
+        ))
+    }
+
+    fn part_2(&self, lines: &Vec<Line>) -> Result<usize, AocError> {
+        Ok(count_overlaps(lines.iter()))
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_core::{parse, AocError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, AocError> {
+        Ok(parse::lines(input)
+            .flat_map(|line| line.text.split(',').map(move |n| line.parse(n.trim())))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(&self, fishes: &Vec<usize>) -> Result<usize, AocError> {
+        let mut fishes = fishes.clone();
+        for _ in 0..80 {
+            transform_school(&mut fishes);
+        }
+        Ok(fishes.len())
+    }
+
+    fn part_2(&self, fishes: &Vec<usize>) -> Result<usize, AocError> {
+        let mut timer_counts = [0; 9];
+        fishes.iter().for_each(|&n| timer_counts[n] += 1);
+
+        for _ in 0..256 {
+            let ready_to_procreate = timer_counts[0];
+            timer_counts.rotate_left(1);
+            timer_counts[6] += ready_to_procreate;
+        }
+        Ok(timer_counts.iter().sum())
+    }
+}
+
+fn transform_school(fishes: &mut Vec<usize>) {
+    let mut new_fishes = 0;
+    for fish in fishes.iter_mut() {
+        match fish {
+            0 => {
+                new_fishes += 1;
+                *fish = 6;
+            }
+            _ => *fish -= 1,
+        }
+    }
+    fishes.extend(vec![8; new_fishes]);
+}
@@ -0,0 +1,48 @@
+use aoc_core::{parse, AocError, Solution};
+use itertools::Itertools;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, AocError> {
+        let positions = parse::lines(input)
+            .flat_map(|line| line.text.split(',').map(move |n| line.parse(n.trim())))
+            .collect::<Result<Vec<usize>, _>>()?;
+        if positions.is_empty() {
+            return Err(AocError::invalid("There are no crabs"));
+        }
+        Ok(positions.into_iter().sorted().collect())
+    }
+
+    fn part_1(&self, positions: &Vec<usize>) -> Result<usize, AocError> {
+        let median = if positions.len().is_multiple_of(2) {
+            positions[(positions.len() / 2) - 1]
+        } else {
+            positions[positions.len() / 2]
+        };
+        Ok(positions.iter().map(|v| v.abs_diff(median)).sum())
+    }
+
+    fn part_2(&self, positions: &Vec<usize>) -> Result<usize, AocError> {
+        let mut min_fuel_usage = 0;
+        for alignmnent in 0..=positions[positions.len() - 1] {
+            let mut fuel = 0;
+            for position in positions {
+                let distance = position.abs_diff(alignmnent);
+                fuel += (distance * (distance + 1)) / 2
+            }
+            if min_fuel_usage == 0 || fuel < min_fuel_usage {
+                min_fuel_usage = fuel;
+            }
+        }
+        Ok(min_fuel_usage)
+    }
+}
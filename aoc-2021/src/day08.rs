@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+
+pub struct Day08;
+
+/// The ten unique signal patterns followed by the four output digits, each
+/// with its segments sorted.
+type Entry = (Vec<String>, Vec<String>);
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    type Input = Vec<Entry>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Entry>, AocError> {
+        Ok(parse::parse_lines(input, parse_entry)?)
+    }
+
+    fn part_1(&self, entries: &Vec<Entry>) -> Result<usize, AocError> {
+        /*
+         * Numbers represented with an unique number of segments
+         * 1 -> 2 segments
+         * 4 -> 4 segments
+         * 7 -> 3 segments
+         * 8 -> 7 segments
+         * */
+        let unique_lengths: HashSet<usize> = HashSet::from_iter([2, 3, 4, 7]);
+        Ok(entries
+            .iter()
+            .map(|(_, output)| {
+                output
+                    .iter()
+                    .filter(|part| unique_lengths.contains(&part.len()))
+                    .count()
+            })
+            .sum())
+    }
+
+    fn part_2(&self, entries: &Vec<Entry>) -> Result<usize, AocError> {
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, (all_segments, output))| {
+                decode(all_segments, output).ok_or_else(|| {
+                    AocError::invalid(format!("Entry {} can't be decoded", index + 1))
+                })
+            })
+            .sum()
+    }
+}
+
+fn parse_entry(line: Line) -> Result<Entry, ParseError> {
+    let (patterns, output) = line
+        .text
+        .split_once(" | ")
+        .ok_or_else(|| line.error(line.text, "Missing ` | `"))?;
+    let sorted_patterns = |text: &str| -> Result<Vec<String>, ParseError> {
+        text.split_whitespace()
+            .map(|pattern| {
+                if !pattern
+                    .chars()
+                    .all(|segment| ('a'..='g').contains(&segment))
+                {
+                    return Err(line.error(pattern, "Segments go from a to g"));
+                }
+                let mut segments = pattern.chars().collect::<Vec<_>>();
+                segments.sort();
+                Ok(segments.iter().collect::<String>())
+            })
+            .collect()
+    };
+    let (all_segments, output) = (sorted_patterns(patterns)?, sorted_patterns(output)?);
+    if all_segments.len() != 10 || output.len() != 4 {
+        return Err(line.error(
+            line.text,
+            "Expected ten signal patterns and four output digits",
+        ));
+    }
+    Ok((all_segments, output))
+}
+
+fn decode(all_segments: &[String], output: &[String]) -> Option<usize> {
+    /*
+     * | segment | number | how to know?                                  | order? |
+     * |---------+--------+-----------------------------------------------+--------|
+     * | ab      |      1 | length 2                                      |      1 |
+     * | eafb    |      4 | length 4                                      |      2 |
+     * | dab     |      7 | length 3                                      |      3 |
+     * | acedgfb |      8 | length 7                                      |      4 |
+     * | cdfbe   |      5 | length 5, has all in (4 - 1)                  |      5 |
+     * | gcdfa   |      2 | length 5, has 2 segments after - 5            |      6 |
+     * | fbcad   |      3 | length 5, has 1 segment  after - 5            |      7 |
+     * | cdfgeb  |      6 | length 6, doesn't have both in 1              |      8 |
+     * | cagedb  |      0 | length 6, has 3 segments after -4 [is not 6]  |      9 |
+     * | cefabd  |      9 | length 6, has 2 segments after -4  [is not 6] |     10 |
+     * */
+    let mut decoding_map: HashMap<&str, usize> = HashMap::new();
+    let mut encoding_map: HashMap<usize, HashSet<char>> = HashMap::new();
+
+    for segment in all_segments {
+        match segment.len() {
+            2 => {
+                decoding_map.insert(segment, 1);
+                encoding_map.insert(1, segment.chars().collect::<HashSet<char>>());
+            }
+            3 => {
+                decoding_map.insert(segment, 7);
+                encoding_map.insert(7, segment.chars().collect::<HashSet<char>>());
+            }
+            4 => {
+                decoding_map.insert(segment, 4);
+                encoding_map.insert(4, segment.chars().collect::<HashSet<char>>());
+            }
+            7 => {
+                decoding_map.insert(segment, 8);
+                encoding_map.insert(8, segment.chars().collect::<HashSet<char>>());
+            }
+            _ => {}
+        }
+    }
+
+    let one = encoding_map.get(&1)?.clone();
+    let four = encoding_map.get(&4)?.clone();
+
+    // get 5
+    let encoded_5 = all_segments
+        .iter()
+        .filter(|segment| segment.len() == 5)
+        .rfind(|segment| {
+            let segment_chars = segment.chars().collect::<HashSet<char>>();
+            let four_minus_1 = four.difference(&one);
+            for character in four_minus_1 {
+                if !segment_chars.contains(character) {
+                    return false;
+                }
+            }
+            true
+        })?;
+    decoding_map.insert(encoded_5, 5);
+    let five = encoded_5.chars().collect::<HashSet<_>>();
+
+    // get 2 and 3
+    all_segments
+        .iter()
+        .filter(|segment| segment.len() == 5)
+        .for_each(|segment| {
+            if let Some(5) = decoding_map.get(segment.as_str()) {
+                return;
+            }
+            let remaining_number = segment
+                .chars()
+                .collect::<HashSet<_>>()
+                .difference(&five)
+                .collect::<HashSet<_>>()
+                .len();
+            match remaining_number {
+                2 => {
+                    decoding_map.insert(segment, 2);
+                    encoding_map.insert(2, segment.chars().collect::<HashSet<_>>());
+                }
+                1 => {
+                    decoding_map.insert(segment, 3);
+                    encoding_map.insert(3, segment.chars().collect::<HashSet<_>>());
+                }
+                _ => {}
+            }
+        });
+
+    // get 6
+    let encoded_6 = all_segments
+        .iter()
+        .filter(|segment| segment.len() == 6)
+        .rfind(|segment| {
+            one.iter()
+                .filter(|character| segment.contains(&character.to_string()))
+                .count()
+                < 2
+        })?;
+    decoding_map.insert(encoded_6, 6);
+    encoding_map.insert(6, encoded_6.chars().collect::<HashSet<_>>());
+
+    // get 0 and 9
+    all_segments
+        .iter()
+        .filter(|segment| segment.len() == 6)
+        .for_each(|segment| {
+            if let Some(6) = decoding_map.get(segment.as_str()) {
+                return;
+            }
+            let remaining_number = segment
+                .chars()
+                .collect::<HashSet<_>>()
+                .difference(&four)
+                .collect::<HashSet<_>>()
+                .len();
+            match remaining_number {
+                2 => {
+                    decoding_map.insert(segment, 9);
+                    encoding_map.insert(9, segment.chars().collect::<HashSet<_>>());
+                }
+                3 => {
+                    decoding_map.insert(segment, 0);
+                    encoding_map.insert(0, segment.chars().collect::<HashSet<_>>());
+                }
+                _ => {}
+            }
+        });
+
+    output.iter().try_fold(0, |result, segment| {
+        let next_digit = decoding_map.get(&segment.as_str())?;
+        Some(result * 10 + next_digit)
+    })
+}
@@ -0,0 +1,67 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{
+    grid::{Grid, Position},
+    AocError, Solution,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    type Input = Grid<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<usize>, AocError> {
+        Ok(Grid::parse(input, |n| {
+            n.to_digit(10).map(|height| height as usize)
+        })?)
+    }
+
+    fn part_1(&self, height_map: &Grid<usize>) -> Result<usize, AocError> {
+        Ok(get_low_points(height_map)
+            .iter()
+            .map(|&position| 1 + height_map[position])
+            .sum())
+    }
+
+    fn part_2(&self, height_map: &Grid<usize>) -> Result<usize, AocError> {
+        let mut basin_sizes = get_low_points(height_map)
+            .iter()
+            .map(|&position| get_basin_size(height_map, position))
+            .collect::<Vec<_>>();
+        basin_sizes.sort();
+        Ok(basin_sizes.iter().rev().take(3).product())
+    }
+}
+
+fn get_low_points(height_map: &Grid<usize>) -> Vec<Position> {
+    height_map
+        .iter()
+        .filter(|&(position, location)| {
+            height_map
+                .neighbours_4(position)
+                .all(|neighbour| height_map[neighbour] > *location)
+        })
+        .map(|(position, _)| position)
+        .collect()
+}
+
+fn get_basin_size(height_map: &Grid<usize>, low_point: Position) -> usize {
+    let mut queue: VecDeque<Position> = VecDeque::new();
+    let mut seen: HashSet<Position> = HashSet::new();
+    queue.push_front(low_point);
+    while let Some(position) = queue.pop_back() {
+        seen.insert(position);
+        for neighbour in height_map.neighbours_4(position) {
+            if !seen.contains(&neighbour) && height_map[neighbour] != 9 {
+                queue.push_front(neighbour);
+            }
+        }
+    }
+    seen.len()
+}
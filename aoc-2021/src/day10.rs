@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use aoc_core::{parse, AocError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(parse::parse_lines(input, |line| {
+            let invalid = line
+                .text
+                .char_indices()
+                .find(|&(_, character)| !"()[]{}<>".contains(character));
+            if let Some((offset, character)) = invalid {
+                let text = &line.text[offset..offset + character.len_utf8()];
+                return Err(line.error(text, "Expected a bracket"));
+            }
+            Ok(line.text.to_string())
+        })?)
+    }
+
+    fn part_1(&self, lines: &Vec<String>) -> Result<usize, AocError> {
+        let character_score: HashMap<char, usize> =
+            HashMap::from_iter([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
+        Ok(lines
+            .iter()
+            .filter_map(|line| get_first_invalid_character(line))
+            .map(|character| character_score[&character])
+            .sum())
+    }
+
+    fn part_2(&self, lines: &Vec<String>) -> Result<usize, AocError> {
+        let character_score: HashMap<char, usize> =
+            HashMap::from_iter([(')', 1), (']', 2), ('}', 3), ('>', 4)]);
+        let mut scores: Vec<usize> = lines
+            .iter()
+            .filter_map(|line| get_completion_characters(line))
+            .map(|completion_characters| {
+                let mut score = 0;
+                for character in completion_characters {
+                    score *= 5;
+                    score += character_score[&character];
+                }
+                score
+            })
+            .collect::<Vec<_>>();
+        scores.sort();
+        scores
+            .get(scores.len() / 2)
+            .copied()
+            .ok_or_else(|| AocError::runtime("Every line is corrupted"))
+    }
+}
+
+/// Closing character of `character`, if it opens a chunk.
+fn closing(character: char) -> Option<char> {
+    match character {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+/// Checks the chunks of `line`, returning the first character closing the
+/// wrong chunk, or else the characters still needed to close every chunk,
+/// innermost first.
+fn check_chunks(line: &str) -> Result<Vec<char>, char> {
+    // closing characters of the open chunks
+    let mut stack: Vec<char> = vec![];
+    for character in line.chars() {
+        match closing(character) {
+            Some(closing) => stack.push(closing),
+            None if stack.pop() == Some(character) => {}
+            None => return Err(character),
+        }
+    }
+    stack.reverse();
+    Ok(stack)
+}
+
+fn get_first_invalid_character(line: &str) -> Option<char> {
+    check_chunks(line).err()
+}
+
+fn get_completion_characters(line: &str) -> Option<Vec<char>> {
+    check_chunks(line).ok()
+}
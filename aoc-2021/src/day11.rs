@@ -0,0 +1,86 @@
+use aoc_core::{grid::Grid, AocError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Input = Grid<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<usize>, AocError> {
+        Ok(Grid::parse(input, |c| {
+            c.to_digit(10).map(|energy| energy as usize)
+        })?)
+    }
+
+    fn part_1(&self, grid: &Grid<usize>) -> Result<usize, AocError> {
+        let mut grid = grid.clone();
+        let mut flashes = 0;
+        for _ in 0..100 {
+            flashes += do_step(&mut grid);
+        }
+        Ok(flashes)
+    }
+
+    fn part_2(&self, grid: &Grid<usize>) -> Result<usize, AocError> {
+        let mut grid = grid.clone();
+        let mut step = 1;
+        loop {
+            if do_step(&mut grid) == grid.len() {
+                return Ok(step);
+            }
+            step += 1;
+        }
+    }
+}
+
+fn do_step(grid: &mut Grid<usize>) -> usize {
+    let mut flash_count = 0;
+
+    // increase all
+    for octopus in grid.values_mut() {
+        *octopus += 1;
+        if *octopus > 9 {
+            flash_count += 1;
+        }
+    }
+
+    // flash adjacent
+    loop {
+        let mut this_loops_flashes = 0;
+        for position in grid.positions() {
+            if grid[position] != 10 {
+                continue;
+            }
+            // increase it again so we don't flash it twice
+            grid[position] += 1;
+            let adjacent_positions: Vec<_> = grid.neighbours_8(position).collect();
+            for adjacent in adjacent_positions {
+                if grid[adjacent] > 9 {
+                    continue;
+                }
+                grid[adjacent] += 1;
+                if grid[adjacent] > 9 {
+                    flash_count += 1;
+                    this_loops_flashes += 1;
+                }
+            }
+        }
+        if this_loops_flashes == 0 {
+            break;
+        }
+    }
+
+    // reset
+    for octopus in grid.values_mut() {
+        if *octopus > 9 {
+            *octopus = 0;
+        }
+    }
+
+    flash_count
+}
@@ -0,0 +1,143 @@
+use std::{collections::HashMap, fmt};
+
+use aoc_core::{
+    parse::{self, ParseError},
+    search::{Search, SearchSpace},
+    AocError, Solution,
+};
+
+pub struct Day12;
+
+pub type CaveMap = HashMap<Cave, Vec<Cave>>;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum Cave {
+    Start,
+    Small(String),
+    Large(String),
+    End,
+}
+
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Cave::Start => "start",
+            Cave::End => "end",
+            Cave::Small(s) | Cave::Large(s) => s.as_ref(),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl From<&str> for Cave {
+    fn from(value: &str) -> Self {
+        match value {
+            "start" => Cave::Start,
+            "end" => Cave::End,
+            val if val.chars().filter(|c| c.is_lowercase()).count() > 0 => {
+                Cave::Small(value.to_string())
+            }
+            _ => Cave::Large(value.to_string()),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<CaveMap, ParseError> {
+    let mut cave_map: CaveMap = HashMap::new();
+    for line in parse::lines(input) {
+        let (source, destination) = line
+            .text
+            .split_once('-')
+            .ok_or_else(|| line.error(line.text, "Expected two caves joined by '-'"))?;
+        let source: Cave = source.into();
+        let destination: Cave = destination.into();
+        cave_map
+            .entry(source.clone())
+            .or_default()
+            .push(destination.clone());
+        cave_map.entry(destination).or_default().push(source);
+    }
+    Ok(cave_map)
+}
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    type Input = CaveMap;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<CaveMap, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, cave_map: &CaveMap) -> Result<usize, AocError> {
+        Ok(count_paths(cave_map, false))
+    }
+
+    fn part_2(&self, cave_map: &CaveMap) -> Result<usize, AocError> {
+        Ok(count_paths(cave_map, true))
+    }
+}
+
+/// Where a path is, and the small caves it went through on its way.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+struct Route {
+    cave: Cave,
+    small_caves: Vec<Cave>,
+    revisited: bool,
+}
+
+struct Caves<'a> {
+    map: &'a CaveMap,
+    /// Whether a single small cave may be visited twice.
+    allow_revisit: bool,
+}
+
+impl SearchSpace for Caves<'_> {
+    type State = Route;
+
+    fn neighbours(&self, route: &Route) -> impl IntoIterator<Item = Route> {
+        let empty: &[Cave] = &[];
+        let next_caves = self.map.get(&route.cave).map_or(empty, Vec::as_slice);
+        next_caves.iter().filter_map(move |next_cave| {
+            let mut next = Route {
+                cave: next_cave.clone(),
+                ..route.clone()
+            };
+            match next_cave {
+                Cave::Start => return None,
+                Cave::Small(_) if route.small_caves.contains(next_cave) => {
+                    if !self.allow_revisit || route.revisited {
+                        return None;
+                    }
+                    next.revisited = true;
+                }
+                Cave::Small(_) => next.small_caves.push(next_cave.clone()),
+                Cave::Large(_) | Cave::End => {}
+            }
+            Some(next)
+        })
+    }
+
+    fn is_goal(&self, route: &Route) -> bool {
+        route.cave == Cave::End
+    }
+}
+
+/// Routes never repeat, as they carry the small caves they went through and
+/// large caves are never connected to each other.
+fn count_paths(cave_map: &CaveMap, allow_revisit: bool) -> usize {
+    let caves = Caves {
+        map: cave_map,
+        allow_revisit,
+    };
+    let start = Route {
+        cave: Cave::Start,
+        small_caves: vec![],
+        revisited: false,
+    };
+    Search::new(&caves).without_dedup().count_goals(start)
+}
@@ -0,0 +1,152 @@
+use aoc_core::{
+    grid::Grid,
+    ocr,
+    parse::{self, Line, ParseError},
+    AocError, Solution,
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day13;
+
+/// Transparent paper with a dot on every `true` cell.
+type Paper = Grid<bool>;
+
+fn get_dimensions_from_points(points: &[Point]) -> (usize, usize) {
+    let mut width = 0;
+    let mut height = 0;
+    for point in points {
+        let Point { x, y } = *point;
+        if x > width {
+            width = x;
+        }
+        if y > height {
+            height = y;
+        }
+    }
+    (width + 1, height + 1)
+}
+
+fn paper_from_points(points: &[Point]) -> Paper {
+    let (width, height) = get_dimensions_from_points(points);
+    let mut paper = Grid::new(width, height, false);
+    for point in points {
+        paper[(point.x, point.y)] = true;
+    }
+    paper
+}
+
+#[derive(Debug)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl TryFrom<Line<'_>> for Point {
+    type Error = ParseError;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let [x, y] = line.integers_n()?;
+        Ok(Self { x, y })
+    }
+}
+
+#[derive(Debug)]
+pub enum Fold {
+    AlongX(usize),
+    AlongY(usize),
+}
+
+impl TryFrom<Line<'_>> for Fold {
+    type Error = ParseError;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^fold along ([xy])=(\d+)$").unwrap();
+        }
+        let captures = line.captures(&RE)?;
+        match captures.str(1)? {
+            "x" => Ok(Self::AlongX(captures.get(2)?)),
+            _ => Ok(Self::AlongY(captures.get(2)?)),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Point>, Vec<Fold>), ParseError> {
+    let sections = parse::sections(input);
+    let (points, folds) = match &sections[..] {
+        [points, folds] => (points, folds),
+        _ => {
+            return Err(ParseError {
+                line: 1,
+                column: 1,
+                text: String::new(),
+                message: format!(
+                    "Expected points and folds, found {} sections",
+                    sections.len()
+                ),
+            })
+        }
+    };
+    let points = points
+        .iter()
+        .map(|&line| line.try_into())
+        .collect::<Result<_, _>>()?;
+    let folds = folds
+        .iter()
+        .map(|&line| line.try_into())
+        .collect::<Result<_, _>>()?;
+    Ok((points, folds))
+}
+
+/// Folds the part of the paper right of (or below) the fold line over the
+/// rest, which becomes the whole paper.
+fn fold_paper(paper: &Paper, fold: &Fold) -> Paper {
+    let mirrored = |index: usize, pivot: usize| (2 * pivot).checked_sub(index);
+    let dot = |position| paper.get(position).copied().unwrap_or(false);
+    match *fold {
+        Fold::AlongX(pivot) => Grid::from_fn(pivot, paper.height(), |(x, y)| {
+            dot((x, y)) || mirrored(x, pivot).is_some_and(|x| dot((x, y)))
+        }),
+        Fold::AlongY(pivot) => Grid::from_fn(paper.width(), pivot, |(x, y)| {
+            dot((x, y)) || mirrored(y, pivot).is_some_and(|y| dot((x, y)))
+        }),
+    }
+}
+
+fn count_visible(paper: &Paper) -> usize {
+    paper.values().filter(|point| **point).count()
+}
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+
+    type Input = (Vec<Point>, Vec<Fold>);
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> Result<(Vec<Point>, Vec<Fold>), AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, (points, folds): &(Vec<Point>, Vec<Fold>)) -> Result<usize, AocError> {
+        let paper = paper_from_points(points);
+        let fold = folds
+            .first()
+            .ok_or_else(|| AocError::invalid("There are no folds"))?;
+        Ok(count_visible(&fold_paper(&paper, fold)))
+    }
+
+    fn part_2(&self, (points, folds): &(Vec<Point>, Vec<Fold>)) -> Result<String, AocError> {
+        let paper = folds.iter().fold(paper_from_points(points), |paper, fold| {
+            fold_paper(&paper, fold)
+        });
+        let rows: Vec<&[bool]> = paper.rows().collect();
+        ocr::read(&rows).map_err(|error| {
+            let drawing = paper.render(|&dot| if dot { '#' } else { ' ' });
+            AocError::runtime(format!("{}\n{}", error, drawing))
+        })
+    }
+}
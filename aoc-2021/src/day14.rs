@@ -0,0 +1,145 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{parse, AocError, Solution};
+
+pub struct Day14;
+
+pub type Transformations = HashMap<(char, char), char>;
+type CharCounter = HashMap<char, usize>;
+type Cache = HashMap<((char, char), usize), CharCounter>;
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Transformations), AocError> {
+    let mut lines = parse::lines(input);
+    let template: Vec<char> = match lines.next() {
+        Some(line) if !line.text.is_empty() => line.text.chars().collect(),
+        _ => return Err(AocError::invalid("The template is empty")),
+    };
+    let mut transformations = Transformations::new();
+    for line in lines.filter(|line| !line.text.is_empty()) {
+        let (pair, element) = parse_rule(line.text)
+            .ok_or_else(|| line.error(line.text, "Expected a rule like `AB -> C`"))?;
+        transformations.insert(pair, element);
+    }
+    Ok((template, transformations))
+}
+
+fn parse_rule(text: &str) -> Option<((char, char), char)> {
+    let (pair, element) = text.split_once(" -> ")?;
+    let [first, second] = pair.chars().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    let [element] = element.chars().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    Some(((first, second), element))
+}
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    type Input = (Vec<char>, Transformations);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<(Vec<char>, Transformations), AocError> {
+        parse_input(input)
+    }
+
+    fn part_1(
+        &self,
+        (template, transformations): &(Vec<char>, Transformations),
+    ) -> Result<usize, AocError> {
+        let mut template = template.clone();
+        for _ in 0..10 {
+            let mut new_template = vec![template[0]];
+            // Iterate wach window and either add the second part, or the newly
+            // synthesized element and then the second part.
+            template.windows(2).for_each(|chunk| {
+                if let Some(target) = transformations.get(&(chunk[0], chunk[1])) {
+                    new_template.extend([*target, chunk[1]]);
+                } else {
+                    new_template.extend([chunk[1]]);
+                };
+            });
+            template = new_template;
+        }
+
+        let element_counter = template
+            .iter()
+            .fold(CharCounter::new(), |mut map, &element| {
+                *map.entry(element).or_default() += 1;
+                map
+            });
+
+        Ok(spread(&element_counter))
+    }
+
+    fn part_2(
+        &self,
+        (template, transformations): &(Vec<char>, Transformations),
+    ) -> Result<usize, AocError> {
+        let target_level = 40;
+        let mut cache = HashMap::new();
+        let mut counter = HashMap::new();
+        for pair in template.windows(2) {
+            let pair_counter = traverse(
+                (pair[0], pair[1]),
+                0,
+                target_level,
+                transformations,
+                &mut cache,
+            );
+            counter = merge_counters(counter, pair_counter);
+        }
+
+        Ok(spread(&counter))
+    }
+}
+
+/// Difference between the most and least common elements.
+fn spread(counter: &CharCounter) -> usize {
+    match (counter.values().max(), counter.values().min()) {
+        (Some(max), Some(min)) => max - min,
+        _ => 0,
+    }
+}
+
+fn merge_counters(counter_a: CharCounter, counter_b: CharCounter) -> CharCounter {
+    let unique_keys_in_both_counters =
+        HashSet::<&char>::from_iter(counter_a.keys().chain(counter_b.keys()));
+    HashMap::from_iter(unique_keys_in_both_counters.iter().map(|&&character| {
+        (
+            character,
+            counter_a.get(&character).unwrap_or(&0) + counter_b.get(&character).unwrap_or(&0),
+        )
+    }))
+}
+
+fn traverse(
+    pair: (char, char),
+    level: usize,
+    target_level: usize,
+    transformations: &Transformations,
+    cache: &mut Cache,
+) -> CharCounter {
+    if let Some(cached) = cache.get(&(pair, level)) {
+        return cached.clone();
+    }
+    let inserted = match transformations.get(&pair) {
+        Some(&inserted) if level < target_level => inserted,
+        _ => return HashMap::from_iter([(pair.1, 1)]),
+    };
+
+    let left_pair = (pair.0, inserted);
+    let left_counter = traverse(left_pair, level + 1, target_level, transformations, cache);
+
+    let right_pair = (inserted, pair.1);
+    let right_counter = traverse(right_pair, level + 1, target_level, transformations, cache);
+
+    let counter = merge_counters(left_counter, right_counter);
+    cache.insert((pair, level), counter.clone());
+
+    counter
+}
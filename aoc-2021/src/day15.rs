@@ -0,0 +1,83 @@
+use aoc_core::{
+    grid::{Grid, Position},
+    search::{Search, SearchSpace},
+    AocError, Solution,
+};
+
+pub struct Day15;
+
+/// Moves across the cave, each costing the risk of the position entered.
+struct Cave<'a> {
+    risks: &'a Grid<usize>,
+    exit: Position,
+}
+
+impl SearchSpace for Cave<'_> {
+    type State = Position;
+
+    fn neighbours(&self, &position: &Position) -> impl IntoIterator<Item = Position> {
+        self.risks.neighbours_4(position).collect::<Vec<_>>()
+    }
+
+    fn cost(&self, _: &Position, &to: &Position) -> u64 {
+        self.risks[to] as u64
+    }
+
+    /// Every position left costs a risk of at least 1.
+    fn heuristic(&self, &(x, y): &Position) -> u64 {
+        (self.exit.0 - x + self.exit.1 - y) as u64
+    }
+
+    fn is_goal(&self, position: &Position) -> bool {
+        *position == self.exit
+    }
+}
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Chiton";
+
+    type Input = Grid<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<usize>, AocError> {
+        let risks = Grid::parse(input, |character| {
+            character.to_digit(10).map(|risk| risk as usize)
+        })?;
+        if risks.is_empty() {
+            return Err(AocError::invalid("The map is empty"));
+        }
+        Ok(risks)
+    }
+
+    fn part_1(&self, risk_grid: &Grid<usize>) -> Result<usize, AocError> {
+        Ok(get_lowest_corner_to_corner_risk(risk_grid))
+    }
+
+    fn part_2(&self, risk_grid: &Grid<usize>) -> Result<usize, AocError> {
+        let risk_grid = increase_grid_size(risk_grid);
+        Ok(get_lowest_corner_to_corner_risk(&risk_grid))
+    }
+}
+
+fn get_lowest_corner_to_corner_risk(risk_grid: &Grid<usize>) -> usize {
+    let cave = Cave {
+        risks: risk_grid,
+        exit: (risk_grid.width() - 1, risk_grid.height() - 1),
+    };
+    let path = Search::new(&cave)
+        .with_buckets()
+        .astar((0, 0))
+        .expect("The exit is always reachable");
+    path.cost as usize
+}
+
+fn increase_grid_size(grid: &Grid<usize>) -> Grid<usize> {
+    let (width, height) = (grid.width(), grid.height());
+    Grid::from_fn(5 * width, 5 * height, |(x, y)| {
+        let risk = grid[(x % width, y % height)] + x / width + y / height;
+        (risk - 1) % 9 + 1
+    })
+}
@@ -0,0 +1,316 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::Chars,
+};
+
+use aoc_core::{AocError, Solution};
+use itertools::Itertools;
+
+pub struct Day16;
+
+pub enum PacketBody {
+    Literal(usize),
+    OpSum(Vec<Packet>),
+    OpProduct(Vec<Packet>),
+    OpMinimum(Vec<Packet>),
+    OpMaximum(Vec<Packet>),
+    OpGreaterThan(Vec<Packet>),
+    OpLessThan(Vec<Packet>),
+    OpEqual(Vec<Packet>),
+}
+
+pub struct Packet {
+    version: usize,
+    type_id: usize,
+    body: PacketBody,
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let body: String = match &self.body {
+            PacketBody::Literal(literal) => format!("{}", literal),
+            PacketBody::OpSum(subpackets)
+            | PacketBody::OpProduct(subpackets)
+            | PacketBody::OpMinimum(subpackets)
+            | PacketBody::OpMaximum(subpackets)
+            | PacketBody::OpGreaterThan(subpackets)
+            | PacketBody::OpLessThan(subpackets)
+            | PacketBody::OpEqual(subpackets) => {
+                subpackets.iter().map(|s| format!("{}", s)).join(", ")
+            }
+        };
+        write!(f, "P<v={},t={}>[{}]", self.version, self.type_id, body)
+    }
+}
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+
+    type Input = Packet;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Packet, AocError> {
+        let binary = parse_input(input)?;
+        let (main_packet, _) = parse_packet(&mut binary.chars())?;
+        Ok(main_packet)
+    }
+
+    fn part_1(&self, main_packet: &Packet) -> Result<usize, AocError> {
+        Ok(get_version_numbers_sum(main_packet))
+    }
+
+    fn part_2(&self, main_packet: &Packet) -> Result<usize, AocError> {
+        Ok(execute(main_packet))
+    }
+}
+
+fn parse_input(input: &str) -> Result<String, AocError> {
+    let nibble_map = HashMap::<char, [char; 4]>::from_iter([
+        ('0', ['0', '0', '0', '0']),
+        ('1', ['0', '0', '0', '1']),
+        ('2', ['0', '0', '1', '0']),
+        ('3', ['0', '0', '1', '1']),
+        ('4', ['0', '1', '0', '0']),
+        ('5', ['0', '1', '0', '1']),
+        ('6', ['0', '1', '1', '0']),
+        ('7', ['0', '1', '1', '1']),
+        ('8', ['1', '0', '0', '0']),
+        ('9', ['1', '0', '0', '1']),
+        ('A', ['1', '0', '1', '0']),
+        ('B', ['1', '0', '1', '1']),
+        ('C', ['1', '1', '0', '0']),
+        ('D', ['1', '1', '0', '1']),
+        ('E', ['1', '1', '1', '0']),
+        ('F', ['1', '1', '1', '1']),
+    ]);
+
+    let mut binary = String::new();
+    for hex_digit in input.trim().chars() {
+        let nibble = nibble_map
+            .get(&hex_digit)
+            .ok_or_else(|| AocError::invalid(format!("Invalid hex digit {:?}", hex_digit)))?;
+        binary.extend(nibble);
+    }
+    Ok(binary)
+}
+
+fn read_number(chars: &mut Chars, bits: usize) -> Result<usize, AocError> {
+    let digits: String = chars.take(bits).collect();
+    if digits.len() < bits {
+        return Err(AocError::invalid(
+            "The transmission ends in the middle of a packet",
+        ));
+    }
+    Ok(usize::from_str_radix(&digits, 2).unwrap())
+}
+
+fn parse_literal_value_packet_body(chars: &mut Chars) -> Result<(PacketBody, usize), AocError> {
+    let mut literal: usize = 0;
+    let mut consumed_count = 0;
+    loop {
+        let group = read_number(chars, 5)?;
+        consumed_count += 5;
+        literal = literal
+            .checked_mul(16)
+            .ok_or_else(|| AocError::invalid("Literal value too big"))?
+            | (group & 0b1111);
+        if group & 0b10000 == 0 {
+            break;
+        }
+    }
+    Ok((PacketBody::Literal(literal), consumed_count))
+}
+
+fn parse_multiple_packets_of_size(
+    chars: &mut Chars,
+    expected_size: usize,
+) -> Result<(Vec<Packet>, usize), AocError> {
+    let mut consumed_size = 0;
+    let mut packets = vec![];
+    while consumed_size < expected_size {
+        let (packet, packet_size) = parse_packet(chars)?;
+        packets.push(packet);
+        consumed_size += packet_size;
+    }
+    Ok((packets, consumed_size))
+}
+
+fn parse_n_packets(
+    chars: &mut Chars,
+    expected_packets: usize,
+) -> Result<(Vec<Packet>, usize), AocError> {
+    let mut packets = vec![];
+    let mut consumed_size = 0;
+    for _ in 0..expected_packets {
+        let (packet, packet_size) = parse_packet(chars)?;
+        consumed_size += packet_size;
+        packets.push(packet)
+    }
+    Ok((packets, consumed_size))
+}
+
+fn parse_operator_packet_body(
+    type_id: usize,
+    chars: &mut Chars,
+) -> Result<(PacketBody, usize), AocError> {
+    let length_type_id = read_number(chars, 1)?;
+    let mut total_consumed = 1;
+    let (packets, inner_consumed) = if length_type_id == 0 {
+        let total_subpacket_length = read_number(chars, 15)?;
+        total_consumed += 15;
+        parse_multiple_packets_of_size(chars, total_subpacket_length)?
+    } else {
+        let number_of_contained_subpackets = read_number(chars, 11)?;
+        total_consumed += 11;
+        parse_n_packets(chars, number_of_contained_subpackets)?
+    };
+    match type_id {
+        2 | 3 if packets.is_empty() => {
+            return Err(AocError::invalid(format!(
+                "Operation {} needs at least one sub-packet",
+                type_id
+            )))
+        }
+        5..=7 if packets.len() != 2 => {
+            return Err(AocError::invalid(format!(
+                "Operation {} compares two sub-packets, not {}",
+                type_id,
+                packets.len()
+            )))
+        }
+        _ => {}
+    }
+    let body = match type_id {
+        0 => PacketBody::OpSum(packets),
+        1 => PacketBody::OpProduct(packets),
+        2 => PacketBody::OpMinimum(packets),
+        3 => PacketBody::OpMaximum(packets),
+        5 => PacketBody::OpGreaterThan(packets),
+        6 => PacketBody::OpLessThan(packets),
+        7 => PacketBody::OpEqual(packets),
+        _ => return Err(AocError::invalid(format!("Unknown operation {}", type_id))),
+    };
+    Ok((body, total_consumed + inner_consumed))
+}
+
+fn parse_packet(chars: &mut Chars) -> Result<(Packet, usize), AocError> {
+    let version = read_number(chars, 3)?;
+    let type_id = read_number(chars, 3)?;
+    let (body, consumed_count) = match type_id {
+        4 => parse_literal_value_packet_body(chars)?,
+        _ => parse_operator_packet_body(type_id, chars)?,
+    };
+    Ok((
+        Packet {
+            version,
+            type_id,
+            body,
+        },
+        consumed_count + 6,
+    ))
+}
+
+fn get_version_numbers_sum(packet: &Packet) -> usize {
+    let mut version_sum: usize = packet.version;
+    match &packet.body {
+        PacketBody::OpSum(subpackets)
+        | PacketBody::OpProduct(subpackets)
+        | PacketBody::OpMinimum(subpackets)
+        | PacketBody::OpMaximum(subpackets)
+        | PacketBody::OpGreaterThan(subpackets)
+        | PacketBody::OpLessThan(subpackets)
+        | PacketBody::OpEqual(subpackets) => {
+            version_sum += subpackets
+                .iter()
+                .map(get_version_numbers_sum)
+                .sum::<usize>();
+        }
+        _ => {}
+    }
+    version_sum
+}
+
+fn execute(packet: &Packet) -> usize {
+    match &packet.body {
+        PacketBody::Literal(value) => *value,
+        PacketBody::OpSum(packets) => packets.iter().map(execute).sum(),
+        PacketBody::OpProduct(packets) => packets.iter().map(execute).product(),
+        // parsing checked that there's at least one sub-packet
+        PacketBody::OpMinimum(packets) => packets.iter().map(execute).min().unwrap_or(0),
+        PacketBody::OpMaximum(packets) => packets.iter().map(execute).max().unwrap_or(0),
+        PacketBody::OpGreaterThan(packets) => {
+            if execute(&packets[0]) > execute(&packets[1]) {
+                1
+            } else {
+                0
+            }
+        }
+        PacketBody::OpLessThan(packets) => {
+            if execute(&packets[0]) < execute(&packets[1]) {
+                1
+            } else {
+                0
+            }
+        }
+        PacketBody::OpEqual(packets) => {
+            if execute(&packets[0]) == execute(&packets[1]) {
+                1
+            } else {
+                0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version_sum(input: &str) -> Result<usize, AocError> {
+        Day16.part_1(&Day16.parse(input)?)
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(version_sum("8A004A801A8002F478"), Ok(16));
+        assert_eq!(version_sum("620080001611562C8802118E34"), Ok(12));
+        assert_eq!(version_sum("C0015000016115A2E0802F182340"), Ok(23));
+        assert_eq!(version_sum("A0016C880162017C3686B18A3D4780"), Ok(31));
+    }
+
+    #[test]
+    fn test_invalid_transmissions() {
+        assert_eq!(
+            version_sum("8A00G"),
+            Err(AocError::invalid("Invalid hex digit 'G'"))
+        );
+        assert_eq!(
+            version_sum("8A004A8"),
+            Err(AocError::invalid(
+                "The transmission ends in the middle of a packet"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_operations() {
+        // a minimum of no sub-packets
+        assert_eq!(
+            version_sum("0A000"),
+            Err(AocError::invalid(
+                "Operation 2 needs at least one sub-packet"
+            ))
+        );
+        // an equality of a single literal
+        assert_eq!(
+            version_sum("1E004408"),
+            Err(AocError::invalid(
+                "Operation 7 compares two sub-packets, not 1"
+            ))
+        );
+    }
+}
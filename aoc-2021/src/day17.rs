@@ -0,0 +1,138 @@
+use aoc_core::{parse, AocError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day17;
+
+#[derive(Debug)]
+pub struct TargetArea {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
+
+#[derive(Debug)]
+struct Probe {
+    x_pos: i32,
+    y_pos: i32,
+    x_vel: i32,
+    y_vel: i32,
+}
+
+impl Probe {
+    fn step(&mut self) {
+        self.x_pos += self.x_vel;
+        self.y_pos += self.y_vel;
+        self.x_vel -= match self.x_vel {
+            x_vel if x_vel < 0 => -1,
+            x_vel if x_vel > 0 => 1,
+            _ => 0,
+        };
+        self.y_vel -= 1;
+    }
+}
+
+fn parse_input(input: &str) -> Result<TargetArea, AocError> {
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new(r"x=(-?[0-9]+)\.\.(-?[0-9]+).*?y=(-?[0-9]+)\.\.(-?[0-9]+)").unwrap();
+    }
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| AocError::invalid("The input is empty"))?;
+    let captures = line.captures(&RE)?;
+    let target_area = TargetArea {
+        x_min: captures.get(1)?,
+        x_max: captures.get(2)?,
+        y_min: captures.get(3)?,
+        y_max: captures.get(4)?,
+    };
+    if target_area.x_min > target_area.x_max || target_area.y_min > target_area.y_max {
+        return Err(line
+            .error(line.text, "Ranges go from the lowest to the highest")
+            .into());
+    }
+    Ok(target_area)
+}
+
+fn area_contains_probe(target_area: &TargetArea, probe: &Probe) -> bool {
+    let TargetArea {
+        x_max,
+        x_min,
+        y_max,
+        y_min,
+    } = target_area;
+    let Probe { x_pos, y_pos, .. } = probe;
+    !(x_pos < x_min || x_pos > x_max || y_pos < y_min || y_pos > y_max)
+}
+
+fn probe_overshot(target_area: &TargetArea, probe: &Probe) -> bool {
+    let TargetArea { x_max, y_min, .. } = target_area;
+    let Probe { x_pos, y_pos, .. } = probe;
+    y_pos < y_min || x_pos > x_max
+}
+
+fn simulate(x_vel: i32, y_vel: i32, target_area: &TargetArea) -> Option<i32> {
+    let mut probe = Probe {
+        x_pos: 0,
+        y_pos: 0,
+        x_vel,
+        y_vel,
+    };
+    let mut highest = 0;
+    loop {
+        probe.step();
+        if probe.y_pos > highest {
+            highest = probe.y_pos;
+        }
+        if area_contains_probe(target_area, &probe) {
+            break;
+        }
+        if probe_overshot(target_area, &probe) {
+            return None;
+        }
+    }
+
+    Some(highest)
+}
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Trick Shot";
+
+    type Input = TargetArea;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<TargetArea, AocError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, target_area: &TargetArea) -> Result<usize, AocError> {
+        let mut total_highest_y = 0;
+        for x_vel in 1..=target_area.x_max {
+            for y_vel in 1..=(target_area.y_min).abs() {
+                if let Some(highest_y) = simulate(x_vel, y_vel, target_area) {
+                    if highest_y > total_highest_y {
+                        total_highest_y = highest_y;
+                    }
+                }
+            }
+        }
+        Ok(total_highest_y as usize)
+    }
+
+    fn part_2(&self, target_area: &TargetArea) -> Result<usize, AocError> {
+        let mut valid_initial_velocity_counter = 0;
+        for x_vel in 1..=target_area.x_max {
+            for y_vel in -(target_area.y_min.abs())..=target_area.y_min.abs() {
+                if simulate(x_vel, y_vel, target_area).is_some() {
+                    valid_initial_velocity_counter += 1;
+                }
+            }
+        }
+        Ok(valid_initial_velocity_counter)
+    }
+}
@@ -0,0 +1,217 @@
+use std::{fmt, fs, path::Path, time::Duration};
+
+use aoc_core::{
+    bench::{measure, Stats},
+    input::Source,
+    Puzzle,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compare,
+    puzzles::{self, Selection},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Step {
+    #[serde(rename = "parse")]
+    Parse,
+    #[serde(rename = "part_1")]
+    Part1,
+    #[serde(rename = "part_2")]
+    Part2,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part_1",
+            Step::Part2 => "part_2",
+        };
+        // `pad` so that table widths apply
+        f.pad(name)
+    }
+}
+
+/// Timing statistics of one step of one day, in nanoseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Timing {
+    fn new(year: u16, day: u8, step: Step, stats: Stats) -> Self {
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        Timing {
+            year,
+            day,
+            step,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            p95_ns: nanos(stats.p95),
+        }
+    }
+}
+
+/// A day that couldn't be benchmarked, and why.
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub year: u16,
+    pub day: u8,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub warmup: usize,
+    pub runs: usize,
+    pub timings: Vec<Timing>,
+    /// Only kept for this run, exports and baselines just have timings.
+    #[serde(skip)]
+    pub failures: Vec<Failure>,
+}
+
+impl Report {
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("year,day,step,min_ns,median_ns,p95_ns\n");
+        for timing in &self.timings {
+            csv += &format!(
+                "{},{},{},{},{},{}\n",
+                timing.year,
+                timing.day,
+                timing.step,
+                timing.min_ns,
+                timing.median_ns,
+                timing.p95_ns
+            );
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Reports are always serializable")
+    }
+}
+
+/// How many times to run each step.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+fn bench_puzzle(puzzle: &dyn Puzzle, options: Options) -> Result<Vec<Timing>, String> {
+    let metadata = puzzle.metadata();
+    let source = Source::default_for(&metadata);
+    let input = source
+        .load()
+        .map_err(|error| format!("Unable to read {}: {}", source, error))?;
+    let Options { warmup, runs } = options;
+
+    // timing a failure would be meaningless, so check every step succeeds first
+    let prepared = puzzle.prepare(&input).map_err(|error| error.to_string())?;
+    for (part, output) in [(1, prepared.part_1()), (2, prepared.part_2())] {
+        output.map_err(|error| format!("part {} failed with {}", part, error))?;
+    }
+
+    let parse = measure(warmup, runs, || puzzle.prepare(&input));
+    let part_1 = measure(warmup, runs, || prepared.part_1());
+    let part_2 = measure(warmup, runs, || prepared.part_2());
+
+    Ok([
+        (Step::Parse, parse),
+        (Step::Part1, part_1),
+        (Step::Part2, part_2),
+    ]
+    .into_iter()
+    .map(|(step, stats)| Timing::new(metadata.year, metadata.day, step, stats))
+    .collect())
+}
+
+/// Benchmarks every selected puzzle, printing each timing as soon as it's
+/// known. Days that can't be benchmarked are reported and skipped, and listed
+/// in the report's failures.
+pub fn collect(selection: Selection, options: Options) -> Result<Report, String> {
+    let puzzles = puzzles::select(selection);
+    if puzzles.is_empty() {
+        return Err("No solved puzzle matches the selection.".to_string());
+    }
+
+    println!(
+        "{:<6}{:<5}{:<8}{:>12}{:>12}{:>12}",
+        "year", "day", "step", "min", "median", "p95"
+    );
+    let mut timings = vec![];
+    let mut failures = vec![];
+    for puzzle in puzzles {
+        let puzzle_timings = match bench_puzzle(puzzle, options) {
+            Ok(puzzle_timings) => puzzle_timings,
+            Err(message) => {
+                let metadata = puzzle.metadata();
+                println!(
+                    "{:<6}{:<5}{}",
+                    metadata.year,
+                    format!("{:02}", metadata.day),
+                    message
+                );
+                failures.push(Failure {
+                    year: metadata.year,
+                    day: metadata.day,
+                    message,
+                });
+                continue;
+            }
+        };
+        for timing in puzzle_timings {
+            println!(
+                "{:<6}{:<5}{:<8}{:>12.2?}{:>12.2?}{:>12.2?}",
+                timing.year,
+                format!("{:02}", timing.day),
+                timing.step,
+                Duration::from_nanos(timing.min_ns),
+                Duration::from_nanos(timing.median_ns),
+                Duration::from_nanos(timing.p95_ns),
+            );
+            timings.push(timing);
+        }
+    }
+    Ok(Report {
+        warmup: options.warmup,
+        runs: options.runs,
+        timings,
+        failures,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents)
+        .map_err(|error| format!("Unable to write {}: {}", path.display(), error))
+}
+
+/// Runs the benchmarks and optionally exports the report, and/or stores it
+/// as the baseline `compare` checks against.
+pub fn bench(
+    selection: Selection,
+    options: Options,
+    json: Option<&Path>,
+    csv: Option<&Path>,
+    baseline: Option<&Path>,
+) -> Result<(), String> {
+    let report = collect(selection, options)?;
+    if let Some(path) = baseline {
+        compare::save(&report, path)?;
+    }
+    if let Some(path) = json {
+        write(path, &report.to_json())?;
+    }
+    if let Some(path) = csv {
+        write(path, &report.to_csv())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use std::{env, fs, io, path::Path};
+
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/ivoscc/rust-aoc";
+
+/// Where and as whom to talk to the Advent of Code site.
+///
+/// Read from `aoc.toml` at the root of the workspace, with the `AOC_BASE_URL`
+/// and `AOC_SESSION` environment variables taking precedence:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Config {
+    #[serde(default = "default_base_url")]
+    pub base_url: String,
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+}
+
+fn default_base_url() -> String {
+    DEFAULT_BASE_URL.to_string()
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_url: default_base_url(),
+            session: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|error| format!("Invalid {}: {}", path.display(), error))?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(error) => return Err(format!("Unable to read {}: {}", path.display(), error)),
+        };
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        let session = config.session.clone().ok_or(
+            "No session token, set AOC_SESSION or `session` in aoc.toml to the `session` \
+             cookie of a logged in browser.",
+        )?;
+        Ok(Client {
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        })
+    }
+
+    fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, page)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Personal puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day, "/input");
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|error| describe(error, year, day))?;
+        response
+            .into_string()
+            .map_err(|error| format!("Unable to read the response of {}: {}", url, error))
+    }
+
+    /// Posts an answer to one part of a day, returning the HTML page the site
+    /// answers with.
+    pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = self.url(year, day, "/answer");
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| describe(error, year, day))?;
+        response
+            .into_string()
+            .map_err(|error| format!("Unable to read the response of {}: {}", url, error))
+    }
+}
+
+/// Explains a failed request in terms of what most likely went wrong.
+fn describe(error: ureq::Error, year: u16, day: u8) -> String {
+    match error {
+        ureq::Error::Status(400, _) => format!(
+            "Bad request for {} day {} (400): the session token is probably invalid or expired.",
+            year, day
+        ),
+        ureq::Error::Status(404, _) => format!(
+            "{} day {} not found (404): the puzzle isn't unlocked yet or doesn't exist.",
+            year, day
+        ),
+        ureq::Error::Status(status, _) if status >= 500 => format!(
+            "Server error for {} day {} ({}): try again later.",
+            year, day, status
+        ),
+        ureq::Error::Status(status, response) => format!(
+            "Unexpected response for {} day {} ({} {}).",
+            year,
+            day,
+            status,
+            response.status_text()
+        ),
+        ureq::Error::Transport(transport) => {
+            format!("Unable to reach the server: {}", transport)
+        }
+    }
+}
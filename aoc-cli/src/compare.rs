@@ -0,0 +1,202 @@
+use std::{collections::HashMap, fs, io, path::Path, time::Duration};
+
+use crate::{
+    bench::{self, Options, Report, Step, Timing},
+    puzzles::Selection,
+};
+
+type Key = (u16, u8, Step);
+
+fn key(timing: &Timing) -> Key {
+    (timing.year, timing.day, timing.step)
+}
+
+/// Reads a baseline previously written by `save`. A missing file is an empty
+/// baseline.
+pub fn load(path: &Path) -> Result<Vec<Timing>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str::<Report>(&contents)
+            .map(|report| report.timings)
+            .map_err(|error| format!("Invalid baseline {}: {}", path.display(), error)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(error) => Err(format!("Unable to read {}: {}", path.display(), error)),
+    }
+}
+
+/// Stores the timings of `report` as the new baseline, keeping the baseline
+/// of any day that wasn't part of it.
+pub fn save(report: &Report, path: &Path) -> Result<(), String> {
+    let mut timings: HashMap<Key, Timing> = load(path)?
+        .into_iter()
+        .map(|timing| (key(&timing), timing))
+        .collect();
+    for timing in &report.timings {
+        timings.insert(key(timing), timing.clone());
+    }
+    let mut timings: Vec<Timing> = timings.into_values().collect();
+    timings.sort_by_key(key);
+
+    let baseline = Report {
+        warmup: report.warmup,
+        runs: report.runs,
+        timings,
+        failures: vec![],
+    };
+    fs::write(path, baseline.to_json())
+        .map_err(|error| format!("Unable to write {}: {}", path.display(), error))
+}
+
+/// When a step counts as a regression.
+#[derive(Debug, Clone, Copy)]
+pub struct Threshold {
+    /// How much slower than the baseline, in percent, a median may get.
+    pub percent: f64,
+    /// Differences below this are noise, whatever the percentage.
+    pub min_difference: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Verdict {
+    Ok,
+    Slower,
+    Faster,
+    New,
+}
+
+fn judge(baseline: Option<&Timing>, current: &Timing, threshold: Threshold) -> Verdict {
+    let Some(baseline) = baseline else {
+        return Verdict::New;
+    };
+    let difference = current.median_ns.abs_diff(baseline.median_ns);
+    let percent = difference as f64 * 100.0 / baseline.median_ns.max(1) as f64;
+    if difference < threshold.min_difference.as_nanos() as u64 || percent <= threshold.percent {
+        Verdict::Ok
+    } else if current.median_ns > baseline.median_ns {
+        Verdict::Slower
+    } else {
+        Verdict::Faster
+    }
+}
+
+/// Benchmarks the selected puzzles and compares their median timings with the
+/// baseline. Fails when any step got slower than `threshold` allows, or when
+/// a day couldn't be benchmarked at all.
+pub fn compare(
+    selection: Selection,
+    options: Options,
+    baseline_path: &Path,
+    threshold: Threshold,
+) -> Result<(), String> {
+    let baseline: HashMap<Key, Timing> = load(baseline_path)?
+        .into_iter()
+        .map(|timing| (key(&timing), timing))
+        .collect();
+    if baseline.is_empty() {
+        return Err(format!(
+            "No baseline in {}, save one with `bench --save-baseline` first.",
+            baseline_path.display()
+        ));
+    }
+    let report = bench::collect(selection, options)?;
+
+    println!(
+        "\n{:<6}{:<5}{:<8}{:>12}{:>12}{:>10}  verdict",
+        "year", "day", "step", "baseline", "median", "change"
+    );
+    let mut regressions = 0;
+    for timing in &report.timings {
+        let previous = baseline.get(&key(timing));
+        let verdict = judge(previous, timing, threshold);
+        let (previous_median, change) = match previous {
+            Some(previous) => (
+                format!("{:.2?}", Duration::from_nanos(previous.median_ns)),
+                format!(
+                    "{:+.1}%",
+                    (timing.median_ns as f64 - previous.median_ns as f64) * 100.0
+                        / previous.median_ns.max(1) as f64
+                ),
+            ),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        if verdict == Verdict::Slower {
+            regressions += 1;
+        }
+        println!(
+            "{:<6}{:<5}{:<8}{:>12}{:>12.2?}{:>10}  {}",
+            timing.year,
+            format!("{:02}", timing.day),
+            timing.step,
+            previous_median,
+            Duration::from_nanos(timing.median_ns),
+            change,
+            format!("{:?}", verdict).to_lowercase()
+        );
+    }
+
+    // a day that stopped working can't be compared, but is worse than slower
+    for failure in &report.failures {
+        println!(
+            "{:<6}{:<5}{:<8}{:>12}{:>12}{:>10}  failed: {}",
+            failure.year,
+            format!("{:02}", failure.day),
+            "-",
+            "-",
+            "-",
+            "-",
+            failure.message
+        );
+    }
+
+    let failures = report.failures.len();
+    if regressions > 0 || failures > 0 {
+        return Err(format!(
+            "{} step(s) got more than {}% slower than the baseline, {} day(s) failed.",
+            regressions, threshold.percent, failures
+        ));
+    }
+    println!("\nNo regressions.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(median_ns: u64) -> Timing {
+        Timing {
+            year: 2015,
+            day: 1,
+            step: Step::Part1,
+            min_ns: median_ns,
+            median_ns,
+            p95_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn test_judge() {
+        let threshold = Threshold {
+            percent: 10.0,
+            min_difference: Duration::from_micros(100),
+        };
+        let baseline = timing(1_000_000);
+        assert_eq!(
+            judge(Some(&baseline), &timing(1_050_000), threshold),
+            Verdict::Ok
+        );
+        assert_eq!(
+            judge(Some(&baseline), &timing(1_200_000), threshold),
+            Verdict::Slower
+        );
+        assert_eq!(
+            judge(Some(&baseline), &timing(800_000), threshold),
+            Verdict::Faster
+        );
+        assert_eq!(judge(None, &timing(800_000), threshold), Verdict::New);
+        // way slower, but by less than the noise floor
+        assert_eq!(
+            judge(Some(&timing(1_000)), &timing(50_000), threshold),
+            Verdict::Ok
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::{fs, path::Path};
+
+use aoc_core::input::default_path;
+
+use crate::{
+    client::{Client, Config},
+    puzzles::{self, Selection},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Cached,
+    Downloaded,
+}
+
+/// Fetches a day's input into `path` unless it's already there. An empty
+/// file, like the one `new-day` creates, doesn't count as a cached input.
+pub fn fetch(client: &Client, year: u16, day: u8, path: &Path) -> Result<Outcome, String> {
+    if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Outcome::Cached);
+    }
+    let input = client.input(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Unable to create {}: {}", parent.display(), error))?;
+    }
+    fs::write(path, input)
+        .map_err(|error| format!("Unable to write {}: {}", path.display(), error))?;
+    Ok(Outcome::Downloaded)
+}
+
+/// Downloads the input of every selected puzzle that doesn't have one yet.
+pub fn download(selection: Selection, config: &Config) -> Result<(), String> {
+    let puzzles = puzzles::select(selection);
+    if puzzles.is_empty() {
+        return Err("No solved puzzle matches the selection.".to_string());
+    }
+    let client = Client::new(config)?;
+    for puzzle in puzzles {
+        let metadata = puzzle.metadata();
+        let path = default_path(&metadata);
+        let outcome = fetch(&client, metadata.year, metadata.day, &path)?;
+        let status = match outcome {
+            Outcome::Cached => "cached",
+            Outcome::Downloaded => "downloaded",
+        };
+        println!("{}: {} ({})", metadata, status, path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::stub::Stub;
+
+    fn client(stub: &Stub) -> Client {
+        Client::new(&Config {
+            base_url: stub.base_url.clone(),
+            session: Some("secret".to_string()),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fetch() {
+        let stub = Stub::start(|request| match request.path.as_str() {
+            "/2015/day/1/input" => (200, "(()\n".to_string()),
+            "/2015/day/2/input" => (400, "Puzzle inputs differ by user.".to_string()),
+            "/2015/day/26/input" => (404, "Not Found".to_string()),
+            _ => (500, "Internal Server Error".to_string()),
+        });
+        let client = client(&stub);
+        let dir = env::temp_dir().join(format!("aoc-download-{}", std::process::id()));
+        let path = dir.join("day01").join("input.txt");
+
+        assert_eq!(fetch(&client, 2015, 1, &path), Ok(Outcome::Downloaded));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "(()\n");
+        assert_eq!(fetch(&client, 2015, 1, &path), Ok(Outcome::Cached));
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1, "cached inputs are never fetched again");
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].body, "");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+
+        let error = fetch(&client, 2015, 2, &dir.join("day02.txt")).unwrap_err();
+        assert!(
+            error.contains("(400)") && error.contains("session"),
+            "{}",
+            error
+        );
+        let error = fetch(&client, 2015, 26, &dir.join("day26.txt")).unwrap_err();
+        assert!(error.contains("(404)"), "{}", error);
+        let error = fetch(&client, 2015, 3, &dir.join("day03.txt")).unwrap_err();
+        assert!(error.contains("(500)"), "{}", error);
+        assert!(!dir.join("day02.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_empty_file_is_not_cached() {
+        let stub = Stub::start(|_| (200, "input".to_string()));
+        let dir = env::temp_dir().join(format!("aoc-download-empty-{}", std::process::id()));
+        let path = dir.join("input.txt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(
+            fetch(&client(&stub), 2021, 1, &path),
+            Ok(Outcome::Downloaded)
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_session() {
+        let error = Client::new(&Config::default()).err().unwrap();
+        assert!(error.contains("AOC_SESSION"), "{}", error);
+    }
+}
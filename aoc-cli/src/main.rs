@@ -0,0 +1,246 @@
+use std::{path::PathBuf, process, time::Duration};
+
+use aoc_core::input::Source;
+use clap::{Args, Parser, Subcommand};
+
+mod bench;
+mod client;
+mod compare;
+mod download;
+mod puzzles;
+mod run;
+mod scaffold;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod verify;
+
+use puzzles::Selection;
+
+/// Runs Advent of Code solutions from every year in the workspace.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve puzzles and print their answers and timings.
+    Run {
+        #[command(flatten)]
+        selection: SelectionArgs,
+        /// Only solve this part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file (`-` for stdin) instead of the
+        /// day's `src/bin/dayNN/input.txt`.
+        #[arg(long)]
+        input: Option<Source>,
+    },
+    /// Check every selected puzzle against the known answers.
+    Verify {
+        #[command(flatten)]
+        selection: SelectionArgs,
+    },
+    /// Time parse, part 1 and part 2 of every selected puzzle over many runs.
+    Bench {
+        #[command(flatten)]
+        selection: SelectionArgs,
+        #[command(flatten)]
+        options: BenchArgs,
+        /// Also write the report as JSON to this file.
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Also write the report as CSV to this file.
+        #[arg(long)]
+        csv: Option<PathBuf>,
+        /// Store the timings as the baseline for `compare`, replacing those
+        /// of the benchmarked days only.
+        #[arg(long)]
+        save_baseline: bool,
+        #[command(flatten)]
+        baseline: BaselineArgs,
+    },
+    /// Benchmark puzzles and flag any step that got slower than the baseline.
+    Compare {
+        #[command(flatten)]
+        selection: SelectionArgs,
+        #[command(flatten)]
+        options: BenchArgs,
+        #[command(flatten)]
+        baseline: BaselineArgs,
+        /// How much slower (in percent) than the baseline a median may get.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Ignore differences smaller than this many microseconds.
+        #[arg(long, default_value_t = 100)]
+        min_difference: u64,
+    },
+    /// Download the input of every selected puzzle that doesn't have one yet.
+    Download {
+        #[command(flatten)]
+        selection: SelectionArgs,
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Submit an answer, unless earlier attempts show it would be rejected.
+    Submit {
+        #[arg(long)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to send, solving the part on its input when missing.
+        answer: Option<String>,
+        #[command(flatten)]
+        config: ConfigArgs,
+        /// Record of every submitted answer.
+        #[arg(long, default_value_os_t = aoc_core::input::workspace_root().join("attempts.json"))]
+        attempts: PathBuf,
+    },
+    /// Create a new day from the template, and its year crate if needed.
+    NewDay {
+        #[arg(long)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle title, as shown on its page.
+        #[arg(long, default_value = "")]
+        title: String,
+    },
+}
+
+#[derive(Args)]
+struct SelectionArgs {
+    /// Only consider puzzles from this year.
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only consider this day (of every selected year).
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+impl From<SelectionArgs> for Selection {
+    fn from(args: SelectionArgs) -> Self {
+        Selection {
+            year: args.year,
+            day: args.day,
+        }
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Untimed runs of each step before measuring.
+    #[arg(long, default_value_t = 1)]
+    warmup: usize,
+    /// Timed runs of each step.
+    #[arg(long, default_value_t = 10, value_parser = at_least_one)]
+    runs: usize,
+}
+
+#[derive(Args)]
+struct BaselineArgs {
+    /// Baseline timings file.
+    #[arg(long = "baseline", default_value_os_t = aoc_core::input::workspace_root().join("baseline.json"))]
+    path: PathBuf,
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    /// Site configuration, with the session token.
+    #[arg(long = "config", default_value_os_t = aoc_core::input::workspace_root().join("aoc.toml"))]
+    path: PathBuf,
+}
+
+impl ConfigArgs {
+    fn load(&self) -> Result<client::Config, String> {
+        client::Config::load(&self.path)
+    }
+}
+
+fn at_least_one(value: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(0) => Err("must be at least 1".to_string()),
+        Ok(value) => Ok(value),
+        Err(error) => Err(format!("{}", error)),
+    }
+}
+
+impl From<BenchArgs> for bench::Options {
+    fn from(args: BenchArgs) -> Self {
+        bench::Options {
+            warmup: args.warmup,
+            runs: args.runs,
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run {
+            selection,
+            part,
+            input,
+        } => run::run(selection.into(), part, input),
+        Command::Verify { selection } => verify::verify(selection.into()),
+        Command::Bench {
+            selection,
+            options,
+            json,
+            csv,
+            save_baseline,
+            baseline,
+        } => bench::bench(
+            selection.into(),
+            options.into(),
+            json.as_deref(),
+            csv.as_deref(),
+            save_baseline.then_some(baseline.path.as_path()),
+        ),
+        Command::Compare {
+            selection,
+            options,
+            baseline,
+            threshold,
+            min_difference,
+        } => compare::compare(
+            selection.into(),
+            options.into(),
+            &baseline.path,
+            compare::Threshold {
+                percent: threshold,
+                min_difference: Duration::from_micros(min_difference),
+            },
+        ),
+        Command::Download { selection, config } => config
+            .load()
+            .and_then(|config| download::download(selection.into(), &config)),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            config,
+            attempts,
+        } => config
+            .load()
+            .and_then(|config| submit::submit(&config, &attempts, (year, day, part), answer)),
+        Command::NewDay { year, day, title } => {
+            let day = scaffold::NewDay { year, day, title };
+            scaffold::new_day(&aoc_core::input::workspace_root(), &day).map(|created| {
+                for path in created {
+                    println!("{}", path.display());
+                }
+            })
+        }
+    };
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
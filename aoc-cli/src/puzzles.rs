@@ -0,0 +1,27 @@
+use aoc_core::{Metadata, Puzzle};
+
+/// Every solved year, in calendar order.
+static YEARS: &[&[&dyn Puzzle]] = &[aoc_2015::PUZZLES, aoc_2016::PUZZLES, aoc_2021::PUZZLES];
+
+/// Which puzzles a command should act on. A missing year or day matches
+/// everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+}
+
+impl Selection {
+    fn matches(&self, metadata: &Metadata) -> bool {
+        self.year.is_none_or(|year| year == metadata.year)
+            && self.day.is_none_or(|day| day == metadata.day)
+    }
+}
+
+pub fn select(selection: Selection) -> Vec<&'static dyn Puzzle> {
+    YEARS
+        .iter()
+        .flat_map(|puzzles| puzzles.iter().copied())
+        .filter(|puzzle| selection.matches(&puzzle.metadata()))
+        .collect()
+}
@@ -0,0 +1,111 @@
+use std::{
+    panic,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{input::Source, Answer, AocError, Puzzle};
+
+use crate::{
+    puzzles::{self, Selection},
+    verify::catch,
+};
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let output = f();
+    (output, start.elapsed())
+}
+
+/// Solves every selected puzzle, printing each answer along with the time it
+/// took. `part` restricts the run to a single part, and `input` overrides the
+/// conventional input file, which only makes sense for a single puzzle.
+/// A puzzle that panics is reported like one that failed, and the others
+/// still run.
+pub fn run(selection: Selection, part: Option<u8>, input: Option<Source>) -> Result<(), String> {
+    let puzzles = puzzles::select(selection);
+    if puzzles.is_empty() {
+        return Err("No solved puzzle matches the selection.".to_string());
+    }
+    if input.is_some() && puzzles.len() > 1 {
+        return Err("An explicit input requires selecting a single puzzle.".to_string());
+    }
+
+    // Panics are printed with the puzzle they happened in, keep the default
+    // hook from also printing them.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut total = Duration::ZERO;
+    for puzzle in puzzles {
+        let source = input
+            .clone()
+            .unwrap_or_else(|| Source::default_for(&puzzle.metadata()));
+        total += run_puzzle(puzzle, part, &source);
+    }
+
+    panic::set_hook(default_hook);
+    println!("Total time: {:.2?}", total);
+    Ok(())
+}
+
+fn run_puzzle(puzzle: &dyn Puzzle, part: Option<u8>, source: &Source) -> Duration {
+    println!("{}", puzzle.metadata());
+
+    let input = match source.load() {
+        Ok(input) => input,
+        Err(error) => {
+            println!("  unable to read {}: {}", source, error);
+            return Duration::ZERO;
+        }
+    };
+
+    let (prepared, parse_time) = timed(|| catch(|| puzzle.prepare(&input)));
+    let prepared = match prepared {
+        Ok(Ok(prepared)) => prepared,
+        Ok(Err(error)) => {
+            println!("  {:<8}{}", "parse", error);
+            return parse_time;
+        }
+        Err(message) => {
+            println!("  {:<8}panicked: {}", "parse", message);
+            return parse_time;
+        }
+    };
+    println!("  {:<8}{:>40}  ({:.2?})", "parse", "", parse_time);
+    let mut elapsed = parse_time;
+
+    if part.is_none_or(|part| part == 1) {
+        let (answer, time) = timed(|| catch(|| prepared.part_1()));
+        print_answer("part 1", &answer, time);
+        elapsed += time;
+    }
+    if part.is_none_or(|part| part == 2) {
+        let (answer, time) = timed(|| catch(|| prepared.part_2()));
+        print_answer("part 2", &answer, time);
+        elapsed += time;
+    }
+    elapsed
+}
+
+fn print_answer(label: &str, answer: &Result<Result<Answer, AocError>, String>, time: Duration) {
+    let answer = match answer {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(error)) => {
+            println!("  {:<8}{}  ({:.2?})", label, error, time);
+            return;
+        }
+        Err(message) => {
+            println!("  {:<8}panicked: {}  ({:.2?})", label, message, time);
+            return;
+        }
+    };
+    match answer {
+        Answer::Grid(rows) => {
+            println!("  {:<8}{:>40}  ({:.2?})", label, "", time);
+            for row in rows {
+                println!("    {}", row);
+            }
+        }
+        _ => println!("  {:<8}{:>40}  ({:.2?})", label, answer, time),
+    }
+}
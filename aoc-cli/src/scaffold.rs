@@ -0,0 +1,449 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const DAY_MAIN_TEMPLATE: &str = include_str!("../templates/day_main.rs");
+const YEAR_CARGO_TEMPLATE: &str = include_str!("../templates/year_Cargo.toml");
+const YEAR_LIB_TEMPLATE: &str = include_str!("../templates/year_lib.rs");
+const YEAR_EXAMPLES_TEMPLATE: &str = include_str!("../templates/year_examples.rs");
+
+const ANSWERS_HEADER: &str =
+    "# Known answers for each day, checked by `aoc verify` and the day binaries.\n";
+const EXPECTED_HEADER: &str =
+    "# Expected outputs of the examples in this directory, keyed by file name.\n";
+
+/// Width rustfmt wraps lines at, generated lists are laid out the same way so
+/// that formatting the workspace leaves them alone.
+const MAX_WIDTH: usize = 100;
+
+/// Which day to create.
+#[derive(Debug, Clone)]
+pub struct NewDay {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+}
+
+impl NewDay {
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{{YEAR}}", &self.year.to_string())
+            .replace("{{DD}}", &format!("{:02}", self.day))
+            .replace("{{DAY}}", &self.day.to_string())
+            .replace("{{TITLE}}", &format!("{:?}", self.title))
+    }
+
+    fn module(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+}
+
+/// Pending change to a single file.
+struct Change {
+    path: PathBuf,
+    contents: String,
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read {}: {}", path.display(), error))
+}
+
+/// Lays out `items` as the array literal ending `prefix`, on one line when it
+/// fits and one item per line otherwise.
+fn array(prefix: &str, items: &[String]) -> String {
+    let line = format!("{}[{}];", prefix, items.join(", "));
+    if line.len() <= MAX_WIDTH {
+        return line;
+    }
+    let mut array = format!("{}[\n", prefix);
+    for item in items {
+        array += &format!("    {},\n", item);
+    }
+    array + "];"
+}
+
+/// Adds `pub mod dayNN;` and its puzzle to a year's `lib.rs`.
+fn register_day(lib: &str, module: &str, day_type: &str) -> Result<String, String> {
+    let declaration = format!("pub mod {};", module);
+    let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&index| lines[index].starts_with("pub mod day"))
+        .collect();
+    match (modules.first(), modules.last()) {
+        (Some(&first), Some(&last)) => {
+            let mut block = lines[first..=last].to_vec();
+            block.push(declaration);
+            block.sort();
+            lines.splice(first..=last, block);
+        }
+        _ => {
+            let index = lines
+                .iter()
+                .position(|line| line.starts_with("/// Every solved day"))
+                .ok_or("Unable to find where to declare the day in lib.rs")?;
+            lines.splice(index..index, [declaration, String::new()]);
+        }
+    }
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static PUZZLES"))
+        .ok_or("Unable to find PUZZLES in lib.rs")?;
+    let end = (start..lines.len())
+        .find(|&index| lines[index].ends_with("];"))
+        .ok_or("Unable to find the end of PUZZLES in lib.rs")?;
+    let mut puzzles: Vec<String> = lines[start..=end]
+        .join(" ")
+        .split(['[', ']', ','])
+        .map(str::trim)
+        .filter(|item| item.starts_with("&day"))
+        .map(str::to_string)
+        .collect();
+    puzzles.push(format!("&{}::{}", module, day_type));
+    puzzles.sort();
+    lines.splice(
+        start..=end,
+        array("pub static PUZZLES: &[&dyn Puzzle] = &", &puzzles)
+            .lines()
+            .map(str::to_string),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `[table]` followed by `body` right before the first table sorting
+/// after it, or at the end.
+fn insert_table(toml: &str, table: &str, body: &str) -> String {
+    let entry = format!("[{}]\n{}", table, body);
+    let position = toml
+        .match_indices("\n[")
+        .map(|(index, _)| index + 1)
+        .find(|&index| {
+            toml[index + 1..]
+                .split(']')
+                .next()
+                .is_some_and(|name| name.trim_matches('"') > table)
+        });
+    match position {
+        Some(index) => format!("{}{}\n{}", &toml[..index], entry, &toml[index..]),
+        None => format!(
+            "{}\n{}",
+            toml.trim_end_matches('\n').to_string() + "\n",
+            entry
+        ),
+    }
+}
+
+/// Adds a crate to the workspace members of the root `Cargo.toml`.
+fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("Unable to find the workspace members")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Unable to find the end of the workspace members")?;
+    let mut members: Vec<&str> = manifest[start + "members = [".len()..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|member| !member.is_empty())
+        .collect();
+    let quoted = format!("{:?}", member);
+    members.push(&quoted);
+    let members = members.join(",\n    ");
+    Ok(format!(
+        "{}members = [\n    {}\n{}",
+        &manifest[..start],
+        members,
+        &manifest[end..]
+    ))
+}
+
+/// Makes the runner depend on a year crate, next to the other years.
+fn add_dependency(manifest: &str, year: u16) -> Result<String, String> {
+    let dependency = format!("aoc-{year} = {{ path = \"../aoc-{year}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let index = lines
+        .iter()
+        .rposition(|line| line.starts_with("aoc-") && *line < dependency.as_str())
+        .map(|index| index + 1)
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|line| *line == "[dependencies]")
+                .map(|index| index + 1)
+        })
+        .ok_or("Unable to find the runner dependencies")?;
+    lines.insert(index, &dependency);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a year crate to the runner's `YEARS`.
+fn add_year(puzzles: &str, year: u16) -> Result<String, String> {
+    let prefix = "static YEARS: &[&[&dyn Puzzle]] = &";
+    let start = puzzles
+        .find(prefix)
+        .ok_or("Unable to find YEARS in the runner")?;
+    let end = start
+        + puzzles[start..]
+            .find("];")
+            .ok_or("Unable to find the end of YEARS in the runner")?
+        + "];".len();
+    let mut years: Vec<String> = puzzles[start + prefix.len()..end]
+        .split(['[', ']', ',', ';'])
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect();
+    years.push(format!("aoc_{}::PUZZLES", year));
+    years.sort();
+    Ok(format!(
+        "{}{}{}",
+        &puzzles[..start],
+        array(prefix, &years),
+        &puzzles[end..]
+    ))
+}
+
+/// Every file to create or update for a year crate that doesn't exist yet.
+fn new_year(root: &Path, year: u16) -> Result<Vec<Change>, String> {
+    let crate_dir = root.join(format!("aoc-{}", year));
+    let render = |template: &str| template.replace("{{YEAR}}", &year.to_string());
+    let workspace = root.join("Cargo.toml");
+    let cli_manifest = root.join("aoc-cli").join("Cargo.toml");
+    let cli_puzzles = root.join("aoc-cli").join("src").join("puzzles.rs");
+
+    Ok(vec![
+        Change {
+            path: crate_dir.join("Cargo.toml"),
+            contents: render(YEAR_CARGO_TEMPLATE),
+        },
+        Change {
+            path: crate_dir.join("src").join("lib.rs"),
+            contents: render(YEAR_LIB_TEMPLATE),
+        },
+        Change {
+            path: crate_dir.join("answers.toml"),
+            contents: ANSWERS_HEADER.to_string(),
+        },
+        Change {
+            path: crate_dir.join("examples").join("expected.toml"),
+            contents: EXPECTED_HEADER.to_string(),
+        },
+        Change {
+            path: crate_dir.join("tests").join("examples.rs"),
+            contents: render(YEAR_EXAMPLES_TEMPLATE),
+        },
+        Change {
+            contents: add_member(&read(&workspace)?, &format!("aoc-{}", year))?,
+            path: workspace,
+        },
+        Change {
+            contents: add_dependency(&read(&cli_manifest)?, year)?,
+            path: cli_manifest,
+        },
+        Change {
+            contents: add_year(&read(&cli_puzzles)?, year)?,
+            path: cli_puzzles,
+        },
+    ])
+}
+
+/// Contents of a file that `changes` may already be creating.
+fn current(changes: &[Change], path: &Path) -> Result<String, String> {
+    match changes.iter().rev().find(|change| change.path == path) {
+        Some(change) => Ok(change.contents.clone()),
+        None => read(path),
+    }
+}
+
+/// Every file to create or update for `day`.
+fn plan(root: &Path, day: &NewDay) -> Result<Vec<Change>, String> {
+    let crate_dir = root.join(format!("aoc-{}", day.year));
+    let module = day.module();
+    let source = crate_dir.join("src").join(format!("{}.rs", module));
+    let bin_dir = crate_dir.join("src").join("bin").join(&module);
+    let example = crate_dir
+        .join("examples")
+        .join(format!("{:02}-example.txt", day.day));
+    for path in [&source, &bin_dir, &example] {
+        if path.exists() {
+            return Err(format!(
+                "{} day {} already exists ({}), refusing to overwrite it.",
+                day.year,
+                day.day,
+                path.display()
+            ));
+        }
+    }
+
+    let mut changes = if crate_dir.join("Cargo.toml").exists() {
+        vec![]
+    } else {
+        new_year(root, day.year)?
+    };
+
+    let lib = crate_dir.join("src").join("lib.rs");
+    let answers = crate_dir.join("answers.toml");
+    let expected = crate_dir.join("examples").join("expected.toml");
+    let lib_contents = register_day(
+        &current(&changes, &lib)?,
+        &module,
+        &format!("Day{:02}", day.day),
+    )?;
+    let answers_contents = insert_table(&current(&changes, &answers)?, &module, "");
+    let expected_contents = insert_table(
+        &current(&changes, &expected)?,
+        &format!("{:02}-example", day.day),
+        "# part_1 =\n# part_2 =\n",
+    );
+
+    changes.extend([
+        Change {
+            path: source,
+            contents: day.render(DAY_TEMPLATE),
+        },
+        Change {
+            path: bin_dir.join("main.rs"),
+            contents: day.render(DAY_MAIN_TEMPLATE),
+        },
+        Change {
+            path: bin_dir.join("input.txt"),
+            contents: String::new(),
+        },
+        Change {
+            path: example,
+            contents: String::new(),
+        },
+        Change {
+            path: lib,
+            contents: lib_contents,
+        },
+        Change {
+            path: answers,
+            contents: answers_contents,
+        },
+        Change {
+            path: expected,
+            contents: expected_contents,
+        },
+    ]);
+    Ok(changes)
+}
+
+/// Creates a new day in the workspace at `root`, along with its year crate
+/// when it's the first day of that year. Nothing is written unless every
+/// change could be prepared, and existing days are never overwritten.
+pub fn new_day(root: &Path, day: &NewDay) -> Result<Vec<PathBuf>, String> {
+    let changes = plan(root, day)?;
+    let mut written = vec![];
+    for change in changes {
+        if let Some(parent) = change.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("Unable to create {}: {}", parent.display(), error))?;
+        }
+        fs::write(&change.path, &change.contents)
+            .map_err(|error| format!("Unable to write {}: {}", change.path.display(), error))?;
+        if !written.contains(&change.path) {
+            written.push(change.path);
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_day() {
+        let lib = YEAR_LIB_TEMPLATE.replace("{{YEAR}}", "2022");
+        let lib = register_day(&lib, "day02", "Day02").unwrap();
+        assert_eq!(
+            lib,
+            "use aoc_core::Puzzle;\n\npub mod day02;\n\n\
+             /// Every solved day of the 2022 event, in calendar order.\n\
+             pub static PUZZLES: &[&dyn Puzzle] = &[&day02::Day02];\n"
+        );
+        let lib = register_day(&lib, "day01", "Day01").unwrap();
+        assert!(lib.contains("pub mod day01;\npub mod day02;\n"));
+        assert!(lib.contains("= &[&day01::Day01, &day02::Day02];"));
+
+        let lib = (3..=9).fold(lib, |lib, day| {
+            register_day(&lib, &format!("day{:02}", day), &format!("Day{:02}", day)).unwrap()
+        });
+        assert!(lib.contains("= &[\n    &day01::Day01,\n    &day02::Day02,\n"));
+        assert!(lib.ends_with("    &day09::Day09,\n];\n"));
+    }
+
+    #[test]
+    fn test_insert_table() {
+        let toml = format!("{}\n[day01]\npart_1 = 1\n\n[day03]\n", ANSWERS_HEADER);
+        assert_eq!(
+            insert_table(&toml, "day02", ""),
+            format!(
+                "{}\n[day01]\npart_1 = 1\n\n[day02]\n\n[day03]\n",
+                ANSWERS_HEADER
+            )
+        );
+        assert_eq!(
+            insert_table(&toml, "day04", ""),
+            format!(
+                "{}\n[day01]\npart_1 = 1\n\n[day03]\n\n[day04]\n",
+                ANSWERS_HEADER
+            )
+        );
+        assert_eq!(
+            insert_table(ANSWERS_HEADER, "day01", ""),
+            format!("{}\n[day01]\n", ANSWERS_HEADER)
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc-cli").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc-core\",\n    \"aoc-cli\"\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc-cli").join("Cargo.toml"),
+            "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nclap = \"4\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc-cli").join("src").join("puzzles.rs"),
+            "static YEARS: &[&[&dyn Puzzle]] = &[];\n",
+        )
+        .unwrap();
+
+        let day = NewDay {
+            year: 2022,
+            day: 7,
+            title: "No Space Left On Device".to_string(),
+        };
+        new_day(&root, &day).unwrap();
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("Cargo.toml").contains("    \"aoc-cli\",\n    \"aoc-2022\"\n]"));
+        assert!(read("aoc-cli/Cargo.toml").contains("aoc-2022 = { path = \"../aoc-2022\" }"));
+        assert!(read("aoc-cli/src/puzzles.rs").contains("= &[aoc_2022::PUZZLES];"));
+        assert!(read("aoc-2022/src/lib.rs").contains("pub mod day07;"));
+        assert!(read("aoc-2022/src/day07.rs").contains("impl Solution for Day07"));
+        assert!(read("aoc-2022/src/bin/day07/main.rs").contains("aoc_core::run(&Day07)"));
+        assert!(read("aoc-2022/answers.toml").ends_with("\n[day07]\n"));
+        assert!(read("aoc-2022/examples/expected.toml").contains("[07-example]\n"));
+        assert!(read("aoc-2022/examples/07-example.txt").is_empty());
+
+        let error = new_day(&root, &day).unwrap_err();
+        assert!(error.contains("refusing to overwrite"), "{}", error);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
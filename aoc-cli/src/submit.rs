@@ -0,0 +1,411 @@
+use std::{
+    fmt, fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use aoc_core::{input::Source, Answer};
+
+use crate::{
+    client::{Client, Config},
+    puzzles::{self, Selection},
+};
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Too many attempts, the answer wasn't checked and can be sent again
+    /// after waiting.
+    RateLimited(#[serde(with = "seconds")] Duration),
+}
+
+mod seconds {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+        }
+    }
+}
+
+/// Text of the `<article>` the site puts its verdict in, without any markup.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for character in article.chars() {
+        match character {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(character),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the "You have 1m 5s left to wait" of a rate limited response.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let before = &text[text.find("You have ")? + "You have ".len()..text.find(" left to wait")?];
+    before
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |wait, amount| {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            let seconds = match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+            Some(wait + Duration::from_secs(seconds))
+        })
+}
+
+/// Classifies the page the site answers a submission with.
+pub fn classify(html: &str) -> Result<Outcome, String> {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if text.contains("You gave an answer too recently") {
+        let wait = parse_wait(&text).ok_or_else(|| format!("Unknown wait in: {}", text))?;
+        Ok(Outcome::RateLimited(wait))
+    } else if text.contains("your answer is too high") {
+        Ok(Outcome::TooHigh)
+    } else if text.contains("your answer is too low") {
+        Ok(Outcome::TooLow)
+    } else if text.contains("That's not the right answer") {
+        Ok(Outcome::Wrong)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Err("That part is either already solved or not unlocked yet.".to_string())
+    } else {
+        Err(format!("Unexpected response: {}", text))
+    }
+}
+
+/// One answer sent to the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Every answer ever submitted, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Attempts {
+    attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    /// Reads the attempts previously written by `save`. A missing file means
+    /// nothing was submitted yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|error| format!("Invalid attempts {}: {}", path.display(), error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("Unable to read {}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).expect("Attempts are always serializable");
+        fs::write(path, json)
+            .map_err(|error| format!("Unable to write {}: {}", path.display(), error))
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    fn of(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| (attempt.year, attempt.day, attempt.part) == (year, day, part))
+    }
+
+    /// Explains why `answer` is not worth sending, if the recorded attempts
+    /// already tell how the site would take it.
+    pub fn refusal(&self, year: u16, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        for attempt in self.of(year, day, part) {
+            let previous = attempt.answer.parse::<i128>().ok();
+            match attempt.outcome {
+                Outcome::Correct => {
+                    return Some(format!("Already solved, the answer is {}.", attempt.answer))
+                }
+                Outcome::RateLimited(wait) => {
+                    let until = attempt.submitted_at + wait.as_secs();
+                    if now < until {
+                        return Some(format!(
+                            "Rate limited, wait {}s before submitting again.",
+                            until - now
+                        ));
+                    }
+                }
+                _ if attempt.answer == answer => {
+                    return Some(format!(
+                        "{} was already rejected ({}).",
+                        answer, attempt.outcome
+                    ))
+                }
+                Outcome::TooHigh if number.zip(previous).is_some_and(|(n, p)| n >= p) => {
+                    return Some(format!(
+                        "{} was too high, so {} is too.",
+                        attempt.answer, answer
+                    ))
+                }
+                Outcome::TooLow if number.zip(previous).is_some_and(|(n, p)| n <= p) => {
+                    return Some(format!(
+                        "{} was too low, so {} is too.",
+                        attempt.answer, answer
+                    ))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+/// Sends an answer unless the recorded attempts show it's pointless, and
+/// records how the site took it.
+pub fn submit_with(
+    client: &Client,
+    attempts_path: &Path,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+    now: u64,
+) -> Result<Outcome, String> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err("Refusing to submit an empty answer.".to_string());
+    }
+    let mut attempts = Attempts::load(attempts_path)?;
+    if let Some(refusal) = attempts.refusal(year, day, part, answer, now) {
+        return Err(format!("Not submitted: {}", refusal));
+    }
+
+    let outcome = classify(&client.answer(year, day, part, answer)?)?;
+    attempts.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+        submitted_at: now,
+    });
+    attempts.save(attempts_path)?;
+    Ok(outcome)
+}
+
+/// Solves one part on its conventional input.
+fn solve(year: u16, day: u8, part: u8) -> Result<Answer, String> {
+    let selection = Selection {
+        year: Some(year),
+        day: Some(day),
+    };
+    let puzzle = puzzles::select(selection)
+        .pop()
+        .ok_or_else(|| format!("{} day {} isn't solved yet.", year, day))?;
+    let source = Source::default_for(&puzzle.metadata());
+    let input = source
+        .load()
+        .map_err(|error| format!("Unable to read {}: {}", source, error))?;
+    let prepared = puzzle.prepare(&input).map_err(|error| error.to_string())?;
+    let answer = match part {
+        1 => prepared.part_1(),
+        _ => prepared.part_2(),
+    };
+    answer.map_err(|error| format!("Part {} failed with {}", part, error))
+}
+
+/// Submits `answer` for one part, or the answer the day's solution computes
+/// when there's none, and prints the outcome. Only a correct answer counts as
+/// a success.
+pub fn submit(
+    config: &Config,
+    attempts_path: &Path,
+    puzzle: (u16, u8, u8),
+    answer: Option<String>,
+) -> Result<(), String> {
+    let (year, day, part) = puzzle;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let answer = solve(year, day, part)?;
+            if !answer.is_submittable() {
+                return Err(format!(
+                    "Part {} has no answer to submit:\n{}",
+                    part, answer
+                ));
+            }
+            answer.to_string()
+        }
+    };
+    let client = Client::new(config)?;
+    let outcome = submit_with(&client, attempts_path, puzzle, &answer, now())?;
+    println!(
+        "{} day {:02} part {}: {} is {}",
+        year, day, part, answer, outcome
+    );
+    match outcome {
+        Outcome::Correct => Ok(()),
+        _ => Err("The answer wasn't accepted.".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::stub::Stub;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_classify() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        let too_high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2015/day/1\">[Return to Day 1]</a>");
+        let too_low = page("That's not the right answer; your answer is too low.  please wait one minute before trying again.");
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        let limited = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2015/day/1\">[Return to Day 1]</a>");
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+
+        assert_eq!(classify(&correct), Ok(Outcome::Correct));
+        assert_eq!(classify(&too_high), Ok(Outcome::TooHigh));
+        assert_eq!(classify(&too_low), Ok(Outcome::TooLow));
+        assert_eq!(classify(&wrong), Ok(Outcome::Wrong));
+        assert_eq!(
+            classify(&limited),
+            Ok(Outcome::RateLimited(Duration::from_secs(65)))
+        );
+        assert!(classify(&solved).unwrap_err().contains("already solved"));
+        assert!(classify("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_refusal() {
+        let mut attempts = Attempts::default();
+        let attempt = |answer: &str, outcome, submitted_at| Attempt {
+            year: 2015,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at,
+        };
+        attempts.record(attempt("100", Outcome::TooHigh, 0));
+        attempts.record(attempt("10", Outcome::TooLow, 60));
+        attempts.record(attempt("abc", Outcome::Wrong, 120));
+        attempts.record(attempt(
+            "50",
+            Outcome::RateLimited(Duration::from_secs(30)),
+            200,
+        ));
+
+        assert!(attempts.refusal(2015, 1, 1, "50", 210).is_some());
+        assert_eq!(attempts.refusal(2015, 1, 1, "50", 230), None);
+        assert!(attempts.refusal(2015, 1, 1, "100", 300).is_some());
+        assert!(attempts.refusal(2015, 1, 1, "150", 300).is_some());
+        assert!(attempts.refusal(2015, 1, 1, "5", 300).is_some());
+        assert!(attempts.refusal(2015, 1, 1, "abc", 300).is_some());
+        assert_eq!(attempts.refusal(2015, 1, 1, "xyz", 300), None);
+        // other parts are unaffected
+        assert_eq!(attempts.refusal(2015, 1, 2, "100", 300), None);
+
+        attempts.record(attempt("42", Outcome::Correct, 400));
+        assert!(attempts.refusal(2015, 1, 1, "43", 500).is_some());
+    }
+
+    #[test]
+    fn test_submit() {
+        let stub = Stub::start(|request| {
+            let message = match request.body.as_str() {
+                "level=1&answer=280" => "That's the right answer!",
+                "level=2&answer=2000" => "That's not the right answer; your answer is too high.",
+                _ => "You gave an answer too recently; You have 30s left to wait.",
+            };
+            (200, page(message))
+        });
+        let client = Client::new(&Config {
+            base_url: stub.base_url.clone(),
+            session: Some("secret".to_string()),
+        })
+        .unwrap();
+        let path = env::temp_dir().join(format!("aoc-attempts-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            submit_with(&client, &path, (2015, 1, 1), "280", 0),
+            Ok(Outcome::Correct)
+        );
+        assert_eq!(
+            submit_with(&client, &path, (2015, 1, 2), "2000", 10),
+            Ok(Outcome::TooHigh)
+        );
+        let error = submit_with(&client, &path, (2015, 1, 2), "3000", 80).unwrap_err();
+        assert!(error.contains("too high"), "{}", error);
+        assert_eq!(
+            submit_with(&client, &path, (2015, 1, 2), "1000", 80),
+            Ok(Outcome::RateLimited(Duration::from_secs(30)))
+        );
+        let error = submit_with(&client, &path, (2015, 1, 2), "1000", 100).unwrap_err();
+        assert!(error.contains("wait 10s"), "{}", error);
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 3, "refused answers are never sent");
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2015/day/1/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        assert_eq!(Attempts::load(&path).unwrap().attempts.len(), 3);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
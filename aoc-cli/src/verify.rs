@@ -0,0 +1,166 @@
+use std::{
+    any::Any,
+    collections::{hash_map::Entry, HashMap},
+    panic::{self, AssertUnwindSafe},
+};
+
+use aoc_core::{
+    answers::{Answers, Check},
+    input::Source,
+    Answer, AocError, Metadata, Puzzle,
+};
+
+use crate::puzzles::{self, Selection};
+
+enum Status {
+    Checked(Check, Answer),
+    /// The day returned an error.
+    Failed(String),
+    Panicked(String),
+    /// The puzzle input couldn't be read, so there was nothing to solve.
+    NoInput(String),
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Checked(Check::Pass, _) => "pass",
+            Status::Checked(Check::Fail(_), _) => "fail",
+            Status::Checked(Check::Missing, _) => "missing",
+            Status::Failed(_) => "error",
+            Status::Panicked(_) => "panicked",
+            Status::NoInput(_) => "no input",
+        }
+    }
+
+    fn detail(&self) -> String {
+        match self {
+            Status::Checked(Check::Fail(expected), output) => {
+                format!("{} (expected {})", output, expected)
+            }
+            Status::Checked(_, output) => output.to_string(),
+            Status::Failed(message) | Status::Panicked(message) | Status::NoInput(message) => {
+                message.clone()
+            }
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Runs `f`, turning a panic into its message.
+pub(crate) fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+/// Solves both parts of a puzzle on its conventional input and checks them
+/// against the known answers. Errors and panics are reported instead of
+/// propagated.
+fn verify_puzzle(puzzle: &dyn Puzzle, answers: &Answers) -> [Status; 2] {
+    let metadata = puzzle.metadata();
+    let input = match Source::default_for(&metadata).load() {
+        Ok(input) => input,
+        Err(error) => {
+            let message = format!("unable to read input: {}", error);
+            return [Status::NoInput(message.clone()), Status::NoInput(message)];
+        }
+    };
+    let prepared = match catch(|| puzzle.prepare(&input)) {
+        Ok(Ok(prepared)) => prepared,
+        Ok(Err(error)) => {
+            let message = error.to_string();
+            return [Status::Failed(message.clone()), Status::Failed(message)];
+        }
+        Err(message) => return [Status::Panicked(message.clone()), Status::Panicked(message)],
+    };
+
+    let check = |part: u8, output: Result<Result<Answer, AocError>, String>| match output {
+        Ok(Ok(output)) => {
+            Status::Checked(Check::new(answers.get(metadata.day, part), &output), output)
+        }
+        Ok(Err(error)) => Status::Failed(error.to_string()),
+        Err(message) => Status::Panicked(message),
+    };
+    [
+        check(1, catch(|| prepared.part_1())),
+        check(2, catch(|| prepared.part_2())),
+    ]
+}
+
+fn print_row(metadata: &Metadata, part: u8, status: &Status) {
+    let detail = status.detail();
+    let mut lines = detail.lines();
+    println!(
+        "{:<6}{:<5}{:<6}{:<10}{}",
+        metadata.year,
+        format!("{:02}", metadata.day),
+        part,
+        status.label(),
+        lines.next().unwrap_or_default()
+    );
+    // grids go on their own lines, under the answer column
+    for line in lines {
+        println!("{:27}{}", "", line);
+    }
+}
+
+/// Runs every selected puzzle and prints a table with the outcome of each
+/// part. Fails when any part is wrong, returned an error or panicked.
+pub fn verify(selection: Selection) -> Result<(), String> {
+    let puzzles = puzzles::select(selection);
+    if puzzles.is_empty() {
+        return Err("No solved puzzle matches the selection.".to_string());
+    }
+
+    let mut answers: HashMap<u16, Answers> = HashMap::new();
+    for puzzle in &puzzles {
+        let year = puzzle.metadata().year;
+        if let Entry::Vacant(entry) = answers.entry(year) {
+            entry.insert(Answers::load(year)?);
+        }
+    }
+    let mut counts: HashMap<&'static str, usize> = HashMap::new();
+
+    // Panics are reported in the table, keep the default hook from also
+    // printing them while verifying.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    println!(
+        "{:<6}{:<5}{:<6}{:<10}answer",
+        "year", "day", "part", "status"
+    );
+    for puzzle in puzzles {
+        let metadata = puzzle.metadata();
+        let statuses = verify_puzzle(puzzle, &answers[&metadata.year]);
+        for (part, status) in (1..=2).zip(statuses.iter()) {
+            print_row(&metadata, part, status);
+            *counts.entry(status.label()).or_default() += 1;
+        }
+    }
+
+    panic::set_hook(default_hook);
+
+    let count = |label| counts.get(label).copied().unwrap_or_default();
+    println!(
+        "\n{} passed, {} failed, {} missing, {} errors, {} panicked, {} without input",
+        count("pass"),
+        count("fail"),
+        count("missing"),
+        count("error"),
+        count("panicked"),
+        count("no input")
+    );
+    if count("fail") + count("error") + count("panicked") > 0 {
+        return Err("Some answers are wrong.".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use aoc_core::{AocError, Solution};
+
+pub struct Day{{DD}};
+
+impl Solution for Day{{DD}} {
+    const YEAR: u16 = {{YEAR}};
+    const DAY: u8 = {{DAY}};
+    const TITLE: &'static str = {{TITLE}};
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part_1(&self, lines: &Vec<String>) -> Result<usize, AocError> {
+        Ok(lines.len())
+    }
+
+    fn part_2(&self, lines: &Vec<String>) -> Result<usize, AocError> {
+        Ok(lines.len())
+    }
+}
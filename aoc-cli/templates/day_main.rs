@@ -0,0 +1,5 @@
+use aoc_{{YEAR}}::day{{DD}}::Day{{DD}};
+
+fn main() {
+    aoc_core::run(&Day{{DD}});
+}
@@ -0,0 +1,4 @@
+use aoc_core::Puzzle;
+
+/// Every solved day of the {{YEAR}} event, in calendar order.
+pub static PUZZLES: &[&dyn Puzzle] = &[];
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Output of a puzzle part, whatever type the day computes it as.
+///
+/// Integers that fit an `i64` are always `Integer`, so that answers compare
+/// equal no matter which integer type produced them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    /// Rows of an image drawn by the puzzle, e.g. letters on a screen.
+    Grid(Vec<String>),
+    /// The part has no answer to compute, like every day 25 part 2.
+    None,
+}
+
+impl Answer {
+    pub fn grid<S: Into<String>>(rows: impl IntoIterator<Item = S>) -> Self {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    fn from_i128(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::BigInteger(value),
+        }
+    }
+
+    /// Whether the answer can be typed into the site as is.
+    pub fn is_submittable(&self) -> bool {
+        !matches!(self, Answer::Grid(_) | Answer::None)
+    }
+}
+
+macro_rules! from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::from_i128(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::from_i128(i128::try_from(value).expect("Answer too big for an i128"))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::None
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => fmt::Display::fmt(value, f),
+            Answer::BigInteger(value) => fmt::Display::fmt(value, f),
+            Answer::Text(value) => f.pad(value),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+            Answer::None => f.pad("(none)"),
+        }
+    }
+}
+
+/// Integers and text serialize as themselves, grids as a list of rows and
+/// a missing answer as unit (`null` in JSON).
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(value) => serializer.serialize_i64(*value),
+            Answer::BigInteger(value) => serializer.serialize_i128(*value),
+            Answer::Text(value) => serializer.serialize_str(value),
+            Answer::Grid(rows) => {
+                let mut seq = serializer.serialize_seq(Some(rows.len()))?;
+                for row in rows {
+                    seq.serialize_element(row)?;
+                }
+                seq.end()
+            }
+            Answer::None => serializer.serialize_unit(),
+        }
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer, a string, a list of rows or nothing")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Answer, E> {
+        i128::try_from(value)
+            .map(Answer::from)
+            .map_err(|_| E::custom("answer too big for an i128"))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Answer, A::Error> {
+        let mut rows = vec![];
+        while let Some(row) = seq.next_element::<String>()? {
+            rows.push(row);
+        }
+        Ok(Answer::Grid(rows))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(12usize), Answer::Integer(12));
+        assert_eq!(Answer::from(-3i32), Answer::Integer(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(7i128), Answer::Integer(7));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+        assert_eq!(Answer::from(()), Answer::None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{:>4}", Answer::Integer(12)), "  12");
+        assert_eq!(format!("{:>4}", Answer::from("ab")), "  ab");
+        assert_eq!(Answer::grid(["#.", ".#"]).to_string(), "#.\n.#");
+        assert_eq!(Answer::None.to_string(), "(none)");
+    }
+
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Parts {
+            part_1: Answer,
+            part_2: Answer,
+        }
+        let parts: Parts = toml::from_str("part_1 = 280\npart_2 = \"hepxxyzz\"").unwrap();
+        assert_eq!(parts.part_1, Answer::Integer(280));
+        assert_eq!(parts.part_2, Answer::from("hepxxyzz"));
+
+        for answer in [
+            Answer::Integer(-1),
+            Answer::from(u64::MAX),
+            Answer::from("abc"),
+            Answer::grid(["#.", ".#"]),
+            Answer::None,
+        ] {
+            let json = serde_json::to_string(&answer).unwrap();
+            assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+        }
+        assert_eq!(serde_json::to_string(&Answer::None).unwrap(), "null");
+    }
+}
@@ -0,0 +1,120 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{input::workspace_root, Answer};
+
+/// Unknown keys are rejected, so that a typo like `part1` isn't silently
+/// ignored.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+}
+
+/// Known answers of a whole year, keyed by `dayNN` tables:
+///
+/// ```toml
+/// [day01]
+/// part_1 = 280
+/// part_2 = "hepxxyzz"
+/// ```
+///
+/// A part without an entry simply has no known answer yet.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|error| error.to_string())
+    }
+
+    /// Loads `aoc-YYYY/answers.toml`. A year without the file has no known
+    /// answers.
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|error| format!("Invalid {}: {}", path.display(), error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("Unable to read {}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.get_named(&format!("day{:02}", day), part)
+    }
+
+    /// Known answer of a part under any table name, e.g. an example's.
+    pub fn get_named(&self, name: &str, part: u8) -> Option<&Answer> {
+        let answers = self.0.get(name)?;
+        match part {
+            1 => answers.part_1.as_ref(),
+            2 => answers.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+/// Location of a year's answers file.
+pub fn path(year: u16) -> PathBuf {
+    workspace_root()
+        .join(format!("aoc-{}", year))
+        .join("answers.toml")
+}
+
+/// Outcome of checking one part against the known answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail(Answer),
+    Missing,
+}
+
+impl Check {
+    pub fn new(expected: Option<&Answer>, output: &Answer) -> Self {
+        match expected {
+            Some(expected) if expected == output => Check::Pass,
+            Some(expected) => Check::Fail(expected.clone()),
+            None => Check::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let answers = Answers::parse("[day03]\npart_1 = 12\npart_2 = \"abc\"\n").unwrap();
+        assert_eq!(answers.get(3, 1), Some(&Answer::Integer(12)));
+        assert_eq!(answers.get(3, 2), Some(&Answer::from("abc")));
+        assert_eq!(answers.get(4, 1), None);
+
+        assert_eq!(
+            Check::new(answers.get(3, 1), &Answer::from(12u64)),
+            Check::Pass
+        );
+        assert_eq!(
+            Check::new(answers.get(3, 2), &Answer::from("abc")),
+            Check::Pass
+        );
+        assert_eq!(
+            Check::new(answers.get(3, 1), &Answer::from(13)),
+            Check::Fail(Answer::Integer(12))
+        );
+        assert_eq!(
+            Check::new(answers.get(4, 1), &Answer::from(13)),
+            Check::Missing
+        );
+
+        assert!(Answers::parse("[day03]\npart1 = 12\n").is_err());
+    }
+}
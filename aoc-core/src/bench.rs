@@ -0,0 +1,69 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Summary of repeated timings of the same piece of work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non empty list of timings.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Can't summarize zero samples");
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        // nearest rank
+        let p95_rank = (samples.len() * 95).div_ceil(100);
+        Stats {
+            min: samples[0],
+            median,
+            p95: samples[p95_rank - 1],
+        }
+    }
+}
+
+/// Times `runs` calls of `f` after `warmup` untimed ones.
+///
+/// Handy to compare two approaches to the same day outside of the runner,
+/// e.g. `measure(3, 20, || math::divisor_sums(limit))`.
+pub fn measure<T>(warmup: usize, runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let samples = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let stats = Stats::from_samples(vec![Duration::from_millis(7)]);
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.p95, Duration::from_millis(7));
+    }
+}
@@ -0,0 +1,681 @@
+//! Circuits of 16-bit wires connected through bitwise gates, written as
+//! `x AND y -> z`, one instruction per line.
+//!
+//! ```ignore
+//! let context = Context::new(circuit::parse_instructions(input)?)?;
+//! let a = context.value("a")?;
+//! let changed = context.override_wires(circuit::parse_instructions("956 -> b")?)?;
+//! std::fs::write("a.dot", context.cone("a")?.to_dot(Signals::Shown))?;
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    fmt::{self, Write},
+};
+
+use crate::{
+    parse::{self, Line, ParseError},
+    AocError,
+};
+
+/// A gate operand: a constant signal or the signal on a wire.
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub enum Operator {
+    Literal(u16),
+    Wire(String),
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(value) => write!(f, "{}", value),
+            Self::Wire(wire) => write!(f, "{}", wire),
+        }
+    }
+}
+
+/// The gate driving a wire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Assign(Operator),
+    Not(Operator),
+    And(Operator, Operator),
+    Or(Operator, Operator),
+    Lshift(Operator, Operator),
+    Rshift(Operator, Operator),
+}
+
+impl Input {
+    pub fn operands(&self) -> Vec<&Operator> {
+        match self {
+            Input::Assign(operator) | Input::Not(operator) => vec![operator],
+            Input::And(left, right)
+            | Input::Or(left, right)
+            | Input::Lshift(left, right)
+            | Input::Rshift(left, right) => vec![left, right],
+        }
+    }
+
+    /// Wires the gate reads from.
+    pub fn wires(&self) -> impl Iterator<Item = &str> {
+        self.operands()
+            .into_iter()
+            .filter_map(|operator| match operator {
+                Operator::Wire(wire) => Some(wire.as_str()),
+                Operator::Literal(_) => None,
+            })
+    }
+
+    /// Output of the gate, given the signals on the wires it reads from.
+    /// Shifting by 16 or more leaves no bits behind.
+    pub fn evaluate(&self, mut signal: impl FnMut(&Operator) -> u16) -> u16 {
+        let shift = |value: u16, amount: u16, shift: fn(u16, u32) -> Option<u16>| {
+            shift(value, amount.into()).unwrap_or(0)
+        };
+        match self {
+            Input::Assign(operator) => signal(operator),
+            Input::Not(operator) => !signal(operator),
+            Input::And(left, right) => signal(left) & signal(right),
+            Input::Or(left, right) => signal(left) | signal(right),
+            Input::Lshift(left, right) => shift(signal(left), signal(right), u16::checked_shl),
+            Input::Rshift(left, right) => shift(signal(left), signal(right), u16::checked_shr),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Assign(operator) => write!(f, "{}", operator),
+            Input::Not(operator) => write!(f, "NOT {}", operator),
+            Input::And(left, right) => write!(f, "{} AND {}", left, right),
+            Input::Or(left, right) => write!(f, "{} OR {}", left, right),
+            Input::Lshift(left, right) => write!(f, "{} LSHIFT {}", left, right),
+            Input::Rshift(left, right) => write!(f, "{} RSHIFT {}", left, right),
+        }
+    }
+}
+
+/// A gate and the wire it drives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub input: Input,
+    pub output: String,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.input, self.output)
+    }
+}
+
+fn parse_operator<'a>(line: &Line<'a>, text: &'a str) -> Result<Operator, ParseError> {
+    if text.bytes().all(|byte| byte.is_ascii_digit()) {
+        line.parse(text)
+            .map(Operator::Literal)
+            .map_err(|_| line.error(text, "Signals are 16 bits"))
+    } else if !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_lowercase()) {
+        Ok(Operator::Wire(text.to_string()))
+    } else {
+        Err(line.error(text, "Invalid wire"))
+    }
+}
+
+fn parse_instruction(line: Line) -> Result<Instruction, ParseError> {
+    let (input, output) = line
+        .text
+        .split_once(" -> ")
+        .ok_or_else(|| line.error(line.text, "Missing ` -> `"))?;
+    let operand = |text| parse_operator(&line, text);
+    let operands: Vec<&str> = input.split(' ').collect();
+    let input = match operands[..] {
+        [value] => Input::Assign(operand(value)?),
+        ["NOT", value] => Input::Not(operand(value)?),
+        [left, "OR", right] => Input::Or(operand(left)?, operand(right)?),
+        [left, "AND", right] => Input::And(operand(left)?, operand(right)?),
+        [left, "LSHIFT", right] => Input::Lshift(operand(left)?, operand(right)?),
+        [left, "RSHIFT", right] => Input::Rshift(operand(left)?, operand(right)?),
+        [_, gate, _] => return Err(line.error(gate, "Unknown gate")),
+        _ => return Err(line.error(input, "Invalid gate input")),
+    };
+    match operand(output)? {
+        Operator::Wire(output) => Ok(Instruction { input, output }),
+        Operator::Literal(_) => Err(line.error(output, "Only wires can be driven")),
+    }
+}
+
+/// One instruction per line.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::parse_lines(input, parse_instruction)
+}
+
+/// Why a circuit can't be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// No instruction drives `wire`, which `used_by` reads from (if any).
+    UndefinedWire {
+        wire: String,
+        used_by: Option<String>,
+    },
+    /// Wires that depend on themselves, each one reading from the next and
+    /// the last one from the first.
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::UndefinedWire {
+                wire,
+                used_by: Some(used_by),
+            } => write!(
+                f,
+                "Wire {} is never set but {} reads from it",
+                wire, used_by
+            ),
+            CircuitError::UndefinedWire {
+                wire,
+                used_by: None,
+            } => write!(f, "Wire {} is never set", wire),
+            CircuitError::Cycle(wires) => {
+                write!(f, "Wires {} depend on themselves", wires.join(" -> "))
+            }
+        }
+    }
+}
+
+impl From<CircuitError> for AocError {
+    fn from(error: CircuitError) -> Self {
+        AocError::invalid(error.to_string())
+    }
+}
+
+/// A circuit with the signal on every wire already evaluated.
+#[derive(Debug, Clone)]
+pub struct Context {
+    instructions_map: HashMap<String, Instruction>,
+    /// Wires in evaluation order, every wire after the ones it reads from.
+    order: Vec<String>,
+    /// Index of each wire in `order`.
+    positions: HashMap<String, usize>,
+    /// Wires reading from each wire, once per operand.
+    readers: HashMap<String, Vec<String>>,
+    cache: HashMap<String, u16>,
+}
+
+impl Context {
+    /// Evaluates the circuit. A wire driven by several instructions keeps the
+    /// last one.
+    pub fn new(instructions: impl IntoIterator<Item = Instruction>) -> Result<Self, CircuitError> {
+        let mut wires = vec![];
+        let mut instructions_map = HashMap::new();
+        for instruction in instructions {
+            if !instructions_map.contains_key(&instruction.output) {
+                wires.push(instruction.output.clone());
+            }
+            instructions_map.insert(instruction.output.clone(), instruction);
+        }
+        let mut context = Context {
+            order: topological_order(&wires, &instructions_map)?,
+            positions: HashMap::new(),
+            readers: HashMap::new(),
+            instructions_map,
+            cache: HashMap::new(),
+        };
+        context.index();
+        for instruction in context.instructions_map.values() {
+            for wire in instruction.input.wires() {
+                context
+                    .readers
+                    .entry(wire.to_string())
+                    .or_default()
+                    .push(instruction.output.clone());
+            }
+        }
+        for position in 0..context.order.len() {
+            let wire = context.order[position].clone();
+            let value = context.compute(&wire);
+            context.cache.insert(wire, value);
+        }
+        Ok(context)
+    }
+
+    fn index(&mut self) {
+        self.positions = self.order.iter().cloned().zip(0..).collect();
+    }
+
+    /// Signal on `wire` from the signals on the wires it reads from, which
+    /// must be up to date.
+    fn compute(&self, wire: &str) -> u16 {
+        self.instructions_map[wire]
+            .input
+            .evaluate(|operator| match operator {
+                Operator::Literal(value) => *value,
+                Operator::Wire(wire) => self.cache[wire],
+            })
+    }
+
+    /// Drives wires with other instructions instead, adding the wires that
+    /// didn't exist, and returns the wires whose signal changed in evaluation
+    /// order. Only the wires reading from a changed one are evaluated again.
+    ///
+    /// The circuit is left as it was if the overrides read from undefined
+    /// wires or make a wire depend on itself.
+    pub fn override_wires(
+        &mut self,
+        overrides: impl IntoIterator<Item = Instruction>,
+    ) -> Result<Vec<String>, CircuitError> {
+        let overrides: Vec<Instruction> = overrides.into_iter().collect();
+        let mut wires = self.order.clone();
+        for instruction in &overrides {
+            if !self.positions.contains_key(&instruction.output)
+                && !wires.contains(&instruction.output)
+            {
+                wires.push(instruction.output.clone());
+            }
+        }
+        // constants can't break the order, but gates may read from wires
+        // evaluated after them or close a loop
+        if overrides
+            .iter()
+            .any(|instruction| instruction.input.wires().next().is_some())
+        {
+            let mut instructions_map = self.instructions_map.clone();
+            for instruction in &overrides {
+                instructions_map.insert(instruction.output.clone(), instruction.clone());
+            }
+            wires = topological_order(&wires, &instructions_map)?;
+        }
+        if wires != self.order {
+            self.order = wires;
+            self.index();
+        }
+
+        let mut queue = BinaryHeap::new();
+        let mut queued = HashSet::new();
+        for instruction in overrides {
+            let output = instruction.output.clone();
+            for wire in instruction.input.wires() {
+                self.readers
+                    .entry(wire.to_string())
+                    .or_default()
+                    .push(output.clone());
+            }
+            let replaced = self.instructions_map.insert(output.clone(), instruction);
+            if let Some(replaced) = replaced {
+                for wire in replaced.input.wires() {
+                    let readers = self.readers.get_mut(wire).expect("Readers are indexed");
+                    let reader = readers
+                        .iter()
+                        .position(|reader| *reader == replaced.output)
+                        .expect("Readers are indexed");
+                    readers.swap_remove(reader);
+                }
+            }
+            // the old signal stays cached to tell whether it changed
+            let position = self.positions[&output];
+            if queued.insert(position) {
+                queue.push(Reverse(position));
+            }
+        }
+
+        let mut changed = vec![];
+        while let Some(Reverse(position)) = queue.pop() {
+            let wire = self.order[position].clone();
+            let value = self.compute(&wire);
+            if self.cache.insert(wire.clone(), value) == Some(value) {
+                continue;
+            }
+            for reader in self.readers.get(&wire).into_iter().flatten() {
+                let position = self.positions[reader];
+                if queued.insert(position) {
+                    queue.push(Reverse(position));
+                }
+            }
+            changed.push(wire);
+        }
+        Ok(changed)
+    }
+
+    /// Signal on `wire`.
+    pub fn value(&self, wire: &str) -> Result<u16, CircuitError> {
+        self.cache
+            .get(wire)
+            .copied()
+            .ok_or_else(|| CircuitError::UndefinedWire {
+                wire: wire.to_string(),
+                used_by: None,
+            })
+    }
+
+    /// Every wire and its signal, in evaluation order.
+    pub fn values(&self) -> impl Iterator<Item = (&str, u16)> {
+        self.order
+            .iter()
+            .map(|wire| (wire.as_str(), self.cache[wire]))
+    }
+
+    /// Instructions in evaluation order.
+    pub fn instructions(&self) -> impl Iterator<Item = &Instruction> {
+        self.order.iter().map(|wire| &self.instructions_map[wire])
+    }
+
+    /// The instructions in evaluation order, one per line, which parse back
+    /// into the same circuit.
+    pub fn netlist(&self) -> String {
+        self.instructions()
+            .map(|instruction| format!("{}\n", instruction))
+            .collect()
+    }
+
+    /// The part of the circuit feeding `wire`, with every wire its signal
+    /// depends on and nothing else.
+    pub fn cone(&self, wire: &str) -> Result<Context, CircuitError> {
+        self.value(wire)?;
+        let mut cone = HashSet::from([wire]);
+        let mut pending = vec![wire];
+        while let Some(wire) = pending.pop() {
+            for input in self.instructions_map[wire].input.wires() {
+                if cone.insert(input) {
+                    pending.push(input);
+                }
+            }
+        }
+        Context::new(
+            self.instructions()
+                .filter(|instruction| cone.contains(instruction.output.as_str()))
+                .cloned(),
+        )
+    }
+
+    /// Graphviz graph of the circuit, with a node per gate and an edge per
+    /// wire from the gate driving it to each gate reading from it. Wires
+    /// nobody reads from lead to a blank node of their own.
+    pub fn to_dot(&self, signals: Signals) -> String {
+        let wire_label = |wire: &str| match signals {
+            Signals::Hidden => wire.to_string(),
+            Signals::Shown => format!("{} = {}", wire, self.cache[wire]),
+        };
+        let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+        for instruction in self.instructions() {
+            let gate = &instruction.output;
+            writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\"];",
+                gate,
+                gate_label(&instruction.input)
+            )
+            .expect("Writing to a String can't fail");
+            if self.readers.get(gate).is_none_or(Vec::is_empty) {
+                writeln!(dot, "    \"{}.out\" [shape=none, label=\"\"];", gate)
+                    .expect("Writing to a String can't fail");
+                writeln!(
+                    dot,
+                    "    \"{}\" -> \"{}.out\" [label=\"{}\"];",
+                    gate,
+                    gate,
+                    wire_label(gate)
+                )
+                .expect("Writing to a String can't fail");
+            }
+        }
+        // edges follow the evaluation order of the gates reading from them, so
+        // the graph is the same every time
+        for instruction in self.instructions() {
+            for wire in instruction.input.wires() {
+                writeln!(
+                    dot,
+                    "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                    wire,
+                    instruction.output,
+                    wire_label(wire)
+                )
+                .expect("Writing to a String can't fail");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Whether graphs label the wires with their signals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signals {
+    Hidden,
+    Shown,
+}
+
+/// The gate's operation and its constant operands, as the wires it reads
+/// from are drawn as edges.
+fn gate_label(input: &Input) -> String {
+    let constant = |operator: &Operator| match operator {
+        Operator::Literal(value) => Some(value.to_string()),
+        Operator::Wire(_) => None,
+    };
+    let (gate, left, right) = match input {
+        Input::Assign(operator) => return constant(operator).unwrap_or_else(|| "=".to_string()),
+        Input::Not(_) => return "NOT".to_string(),
+        Input::And(left, right) => ("AND", left, right),
+        Input::Or(left, right) => ("OR", left, right),
+        Input::Lshift(left, right) => ("LSHIFT", left, right),
+        Input::Rshift(left, right) => ("RSHIFT", left, right),
+    };
+    [constant(left), Some(gate.to_string()), constant(right)]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Orders `wires` so that each one comes after the ones it reads from, by a
+/// depth first search that keeps its own stack to handle long chains.
+fn topological_order(
+    wires: &[String],
+    instructions_map: &HashMap<String, Instruction>,
+) -> Result<Vec<String>, CircuitError> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        Visiting,
+        Done,
+    }
+
+    // popped from the end, so reversed to read them in order
+    let reads_from = |wire: &str| -> Vec<&str> {
+        let mut pending: Vec<&str> = instructions_map[wire].input.wires().collect();
+        pending.reverse();
+        pending
+    };
+    let mut states: HashMap<&str, State> = HashMap::new();
+    let mut order = vec![];
+    for root in wires {
+        if states.contains_key(root.as_str()) {
+            continue;
+        }
+        // wires being visited, with the ones they still have to read from
+        let mut stack: Vec<(&str, Vec<&str>)> = vec![];
+        states.insert(root, State::Visiting);
+        stack.push((root, reads_from(root)));
+        while let Some((wire, pending)) = stack.last_mut() {
+            let wire = *wire;
+            let Some(next) = pending.pop() else {
+                states.insert(wire, State::Done);
+                order.push(wire.to_string());
+                stack.pop();
+                continue;
+            };
+            match states.get(next) {
+                Some(State::Done) => {}
+                Some(State::Visiting) => {
+                    let start = stack
+                        .iter()
+                        .position(|(visiting, _)| *visiting == next)
+                        .expect("Wires being visited are on the stack");
+                    return Err(CircuitError::Cycle(
+                        stack[start..]
+                            .iter()
+                            .map(|(visiting, _)| visiting.to_string())
+                            .collect(),
+                    ));
+                }
+                None => {
+                    if !instructions_map.contains_key(next) {
+                        return Err(CircuitError::UndefinedWire {
+                            wire: next.to_string(),
+                            used_by: Some(wire.to_string()),
+                        });
+                    }
+                    states.insert(next, State::Visiting);
+                    stack.push((next, reads_from(next)));
+                }
+            }
+        }
+    }
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    fn context(input: &str) -> Result<Context, CircuitError> {
+        Context::new(parse_instructions(input).unwrap())
+    }
+
+    #[test]
+    fn test_values() {
+        let context = context(EXAMPLE).unwrap();
+        let values: HashMap<&str, u16> = context.values().collect();
+        assert_eq!(
+            values,
+            HashMap::from([
+                ("d", 72),
+                ("e", 507),
+                ("f", 492),
+                ("g", 114),
+                ("h", 65412),
+                ("i", 65079),
+                ("x", 123),
+                ("y", 456),
+            ])
+        );
+        assert_eq!(context.value("h"), Ok(65412));
+        assert_eq!(
+            context.value("z"),
+            Err(CircuitError::UndefinedWire {
+                wire: "z".to_string(),
+                used_by: None
+            })
+        );
+        // wires are listed after the ones they read from
+        let order: Vec<&str> = context.values().map(|(wire, _)| wire).collect();
+        assert_eq!(order, ["x", "y", "d", "e", "f", "g", "h", "i"]);
+        assert_eq!(
+            context.instructions().nth(2).unwrap().to_string(),
+            "x AND y -> d"
+        );
+    }
+
+    #[test]
+    fn test_overrides() {
+        let mut context = context(EXAMPLE).unwrap();
+        let overrides = parse_instructions("123 -> x\n7 -> y").unwrap();
+        let changed = context.override_wires(overrides).unwrap();
+        assert_eq!(changed, ["y", "d", "e", "g", "i"]);
+        assert_eq!(context.value("e"), Ok(127));
+
+        // gates may read from wires that used to be evaluated after them
+        let overrides = parse_instructions("g AND 3 -> x\nx OR f -> j").unwrap();
+        let changed = context.override_wires(overrides).unwrap();
+        assert_eq!(changed, ["x", "d", "e", "f", "h", "j"]);
+        assert_eq!(context.value("x"), Ok(1));
+        assert_eq!(context.value("j"), Ok(5));
+        assert_eq!(context.instructions().next().unwrap().to_string(), "7 -> y");
+
+        let before: Vec<(String, u16)> = context
+            .values()
+            .map(|(wire, value)| (wire.to_string(), value))
+            .collect();
+        let loop_back = parse_instructions("j -> y").unwrap();
+        assert!(matches!(
+            context.override_wires(loop_back),
+            Err(CircuitError::Cycle(_))
+        ));
+        let undefined = parse_instructions("z -> y").unwrap();
+        assert!(context.override_wires(undefined).is_err());
+        let after: Vec<(String, u16)> = context
+            .values()
+            .map(|(wire, value)| (wire.to_string(), value))
+            .collect();
+        assert_eq!(before, after);
+        assert_eq!(context.override_wires([]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_export() {
+        let context = context(EXAMPLE).unwrap();
+        assert_eq!(
+            Context::new(parse_instructions(&context.netlist()).unwrap())
+                .unwrap()
+                .netlist(),
+            context.netlist()
+        );
+
+        let cone = context.cone("f").unwrap();
+        assert_eq!(cone.netlist(), "123 -> x\nx LSHIFT 2 -> f\n");
+        assert_eq!(
+            cone.to_dot(Signals::Shown),
+            r#"digraph circuit {
+    rankdir=LR;
+    "x" [label="123"];
+    "f" [label="LSHIFT 2"];
+    "f.out" [shape=none, label=""];
+    "f" -> "f.out" [label="f = 492"];
+    "x" -> "f" [label="x = 123"];
+}
+"#
+        );
+        let dot = context.to_dot(Signals::Hidden);
+        assert!(dot.contains("    \"d\" [label=\"AND\"];\n"));
+        assert!(dot.contains("    \"y\" -> \"d\" [label=\"y\"];\n"));
+        assert_eq!(dot.matches("shape=none").count(), 6);
+        assert!(context.cone("z").is_err());
+    }
+
+    #[test]
+    fn test_sixteen_bits() {
+        let context = context("b -> a\n65535 LSHIFT 8 -> b\n1 LSHIFT 16 -> c").unwrap();
+        assert_eq!(context.value("a"), Ok(0xFF00));
+        assert_eq!(context.value("c"), Ok(0));
+        assert!(parse_instructions("65536 -> a").is_err());
+        assert!(parse_instructions("1 -> 2").is_err());
+        assert!(parse_instructions("x XOR y -> z").is_err());
+    }
+
+    #[test]
+    fn test_invalid_circuits() {
+        assert_eq!(
+            context("x AND y -> z\n1 -> x").unwrap_err(),
+            CircuitError::UndefinedWire {
+                wire: "y".to_string(),
+                used_by: Some("z".to_string())
+            }
+        );
+        let error = context("1 -> a\nc OR a -> b\nNOT b -> c").unwrap_err();
+        assert_eq!(
+            error,
+            CircuitError::Cycle(vec!["b".to_string(), "c".to_string()])
+        );
+        assert_eq!(error.to_string(), "Wires b -> c depend on themselves");
+        assert!(matches!(context("a -> a"), Err(CircuitError::Cycle(_))));
+    }
+}
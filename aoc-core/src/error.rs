@@ -0,0 +1,78 @@
+use std::{error, fmt};
+
+use crate::parse::ParseError;
+
+/// Why a day couldn't produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input couldn't be parsed.
+    Parse(ParseError),
+    /// The input parsed, but breaks an assumption of the solution, e.g. a
+    /// reference to something it never defines.
+    Invalid(String),
+    /// Solving failed on a valid input, e.g. no solution was found.
+    Runtime(String),
+}
+
+impl AocError {
+    pub fn invalid(message: impl Into<String>) -> Self {
+        AocError::Invalid(message.into())
+    }
+
+    pub fn runtime(message: impl Into<String>) -> Self {
+        AocError::Runtime(message.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse(error) => write!(f, "parse error: {}", error),
+            AocError::Invalid(message) => write!(f, "invalid input: {}", message),
+            AocError::Runtime(message) => write!(f, "runtime error: {}", message),
+        }
+    }
+}
+
+impl error::Error for AocError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AocError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> Self {
+        AocError::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error: AocError = ParseError {
+            line: 2,
+            column: 5,
+            text: "x".to_string(),
+            message: "Unexpected character".to_string(),
+        }
+        .into();
+        assert_eq!(
+            error.to_string(),
+            "parse error: line 2, column 5: Unexpected character (at \"x\")"
+        );
+        assert_eq!(
+            AocError::invalid("Wire b is never set").to_string(),
+            "invalid input: Wire b is never set"
+        );
+        assert_eq!(
+            AocError::runtime("No path to the exit").to_string(),
+            "runtime error: No path to the exit"
+        );
+    }
+}
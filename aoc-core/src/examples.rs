@@ -0,0 +1,106 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+use crate::{answers::Answers, input::Source, Puzzle};
+
+/// Checks a year's puzzles against the examples from their descriptions.
+///
+/// Each example is a `NN-description.txt` file in `dir`, where `NN` is the
+/// zero padded day. Their expected outputs live in `dir/expected.toml`, in the
+/// same format as `answers.toml` but keyed by file name without extension:
+///
+/// ```toml
+/// [16-sum]
+/// part_2 = 3
+/// ```
+///
+/// Only parts with an expected output are run, as some examples only apply
+/// to one of them. An empty table is a stub still waiting for its outputs, and
+/// is skipped. Returns a description of every mismatch.
+pub fn check(dir: &Path, puzzles: &[&dyn Puzzle]) -> Result<(), Vec<String>> {
+    let expected_path = dir.join("expected.toml");
+    let expected = fs::read_to_string(&expected_path)
+        .map_err(|error| error.to_string())
+        .and_then(|contents| Answers::parse(&contents))
+        .map_err(|error| vec![format!("{}: {}", expected_path.display(), error)])?;
+
+    let mut names = BTreeSet::new();
+    for entry in fs::read_dir(dir).map_err(|error| vec![error.to_string()])? {
+        let path = entry.map_err(|error| vec![error.to_string()])?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.insert(stem.to_string());
+            }
+        }
+    }
+
+    let mut failures = vec![];
+    for name in expected.names() {
+        if !names.contains(name) {
+            failures.push(format!("{}: expected outputs for a missing example", name));
+        }
+    }
+
+    for name in &names {
+        let Some(puzzle) = name
+            .split('-')
+            .next()
+            .and_then(|day| day.parse::<u8>().ok())
+            .and_then(|day| puzzles.iter().find(|puzzle| puzzle.metadata().day == day))
+        else {
+            failures.push(format!("{}: doesn't name a solved day", name));
+            continue;
+        };
+        let source = Source::File(dir.join(format!("{}.txt", name)));
+        let input = match source.load() {
+            Ok(input) => input,
+            Err(error) => {
+                failures.push(format!("{}: {}", name, error));
+                continue;
+            }
+        };
+
+        if !expected.names().any(|expected_name| expected_name == name) {
+            failures.push(format!("{}: no expected outputs", name));
+            continue;
+        }
+        let parts = [1, 2].map(|part| expected.get_named(name, part));
+        if parts.iter().all(Option::is_none) {
+            continue;
+        }
+        let prepared = match puzzle.prepare(&input) {
+            Ok(prepared) => prepared,
+            Err(error) => {
+                failures.push(format!("{}: {}", name, error));
+                continue;
+            }
+        };
+        for (part, expected) in (1..=2).zip(parts) {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let output = match part {
+                1 => prepared.part_1(),
+                _ => prepared.part_2(),
+            };
+            let output = match output {
+                Ok(output) => output,
+                Err(error) => {
+                    failures.push(format!("{} part {}: {}", name, part, error));
+                    continue;
+                }
+            };
+            if *expected != output {
+                failures.push(format!(
+                    "{} part {}: got {}, expected {}",
+                    name, part, output, expected
+                ));
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
@@ -0,0 +1,297 @@
+//! Points and vectors on the integer plane.
+//!
+//! `y` grows downwards like the rows of a [`Grid`](crate::grid::Grid), so
+//! [`Heading::North`] moves to a smaller `y`. Puzzles that only care about
+//! distances or visited points are indifferent to it.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::grid::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Difference between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (other - self).chebyshev()
+    }
+
+    /// Cell of a grid at this point, unless it has a negative coordinate.
+    pub fn position(self) -> Option<Position> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    /// Every point of the line from `self` to `end`, both included.
+    /// Horizontal, vertical and 45° lines cover exactly the cells they cross;
+    /// any other slope is approximated with Bresenham's algorithm.
+    pub fn line_to(self, end: Point) -> impl Iterator<Item = Point> {
+        let delta = end - self;
+        let (dx, dy) = (delta.x.abs(), -delta.y.abs());
+        let step = Vector::new(delta.x.signum(), delta.y.signum());
+        let mut error = dx + dy;
+        let mut current = self;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let point = current;
+            if point == end {
+                done = true;
+            } else {
+                let doubled = 2 * error;
+                if doubled >= dy {
+                    error += dy;
+                    current.x += step.x;
+                }
+                if doubled <= dx {
+                    error += dx;
+                    current.y += step.y;
+                }
+            }
+            Some(point)
+        })
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vector { x, y }
+    }
+
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev(self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+}
+
+impl From<Position> for Point {
+    fn from((x, y): Position) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Parses `x,y`, as most puzzles write their points.
+impl FromStr for Point {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (x, y) = input
+            .split_once(',')
+            .ok_or_else(|| format!("Unable to parse {} as point.", input))?;
+        Ok(Point {
+            x: x.trim()
+                .parse()
+                .map_err(|_| format!("Invalid x in {}", input))?,
+            y: y.trim()
+                .parse()
+                .map_err(|_| format!("Invalid y in {}", input))?,
+        })
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i64) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    pub const ALL: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Heading::North => Heading::West,
+            Heading::East => Heading::North,
+            Heading::South => Heading::East,
+            Heading::West => Heading::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Heading::North => Heading::East,
+            Heading::East => Heading::South,
+            Heading::South => Heading::West,
+            Heading::West => Heading::North,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    /// One step in this heading.
+    pub fn vector(self) -> Vector {
+        match self {
+            Heading::North => Vector::new(0, -1),
+            Heading::East => Vector::new(1, 0),
+            Heading::South => Vector::new(0, 1),
+            Heading::West => Vector::new(-1, 0),
+        }
+    }
+}
+
+/// Arrows (`^>v<`), `UDLR` and `NESW`, the ways puzzles spell headings.
+impl TryFrom<char> for Heading {
+    type Error = String;
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        match character {
+            '^' | 'U' | 'N' => Ok(Heading::North),
+            '>' | 'R' | 'E' => Ok(Heading::East),
+            'v' | 'D' | 'S' => Ok(Heading::South),
+            '<' | 'L' | 'W' => Ok(Heading::West),
+            _ => Err(format!("Invalid heading {:?}", character)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let point = Point::new(3, -4);
+        assert_eq!(point - Point::ORIGIN, Vector::new(3, -4));
+        assert_eq!(point + Vector::new(1, 1) * 2, Point::new(5, -2));
+        assert_eq!(point.manhattan(Point::ORIGIN), 7);
+        assert_eq!(point.chebyshev(Point::new(1, 1)), 5);
+        assert_eq!(point.position(), None);
+        assert_eq!(Point::from((2, 1)).position(), Some((2, 1)));
+        assert_eq!("12, 7".parse(), Ok(Point::new(12, 7)));
+        assert!("12".parse::<Point>().is_err());
+    }
+
+    #[test]
+    fn test_heading() {
+        let mut heading = Heading::North;
+        for expected in [Heading::East, Heading::South, Heading::West, Heading::North] {
+            heading = heading.turn_right();
+            assert_eq!(heading, expected);
+            assert_eq!(heading.turn_left().turn_right(), heading);
+        }
+        assert_eq!(Heading::East.reverse(), Heading::West);
+        let sum = Heading::ALL
+            .iter()
+            .fold(Vector::default(), |sum, heading| sum + heading.vector());
+        assert_eq!(sum, Vector::default());
+        assert_eq!(Heading::try_from('v'), Ok(Heading::South));
+    }
+
+    #[test]
+    fn test_line_to() {
+        let line = |start: Point, end: Point| -> Vec<(i64, i64)> {
+            start.line_to(end).map(|point| (point.x, point.y)).collect()
+        };
+        assert_eq!(
+            line(Point::new(1, 1), Point::new(1, 3)),
+            [(1, 1), (1, 2), (1, 3)]
+        );
+        assert_eq!(
+            line(Point::new(9, 7), Point::new(7, 9)),
+            [(9, 7), (8, 8), (7, 9)]
+        );
+        assert_eq!(line(Point::new(2, 2), Point::new(2, 2)), [(2, 2)]);
+        assert_eq!(
+            line(Point::new(0, 0), Point::new(4, 2)),
+            [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+    }
+}
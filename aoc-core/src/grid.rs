@@ -0,0 +1,347 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::{self, ParseError};
+
+/// Column (`x`) and row (`y`) of a cell, `(0, 0)` being the top left corner.
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangle of cells, sized at runtime and stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Fails unless every row is as long as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(format!(
+                    "Row {} is {} cells wide instead of {}",
+                    y + 1,
+                    row.len(),
+                    width
+                ));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses one cell per character, one row per line. `cell` returns `None`
+    /// for characters that aren't valid cells.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in parse::lines(input) {
+            let row_start = cells.len();
+            for (offset, character) in line.text.char_indices() {
+                let value = cell(character).ok_or_else(|| {
+                    let text = &line.text[offset..offset + character.len_utf8()];
+                    line.error(text, "Unexpected character")
+                })?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(line.error(
+                    line.text,
+                    format!(
+                        "Row is {} cells wide instead of {}",
+                        row_width,
+                        width.unwrap_or_default()
+                    ),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn index_of(&self, (x, y): Position) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// The position `(dx, dy)` away from `position`, if it's still inside
+    /// the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (i64, i64)) -> Option<Position> {
+        let x = usize::try_from(x as i64 + dx).ok()?;
+        let y = usize::try_from(y as i64 + dy).ok()?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// Orthogonally adjacent positions inside the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// Orthogonally and diagonally adjacent positions inside the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Positions of the `width` x `height` rectangle whose top left corner is
+    /// `(x, y)`, clipped to the grid.
+    pub fn rect(
+        &self,
+        (x, y): Position,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = Position> {
+        let right = (x + width).min(self.width);
+        let bottom = (y + height).min(self.height);
+        (y..bottom).flat_map(move |y| (x..right).map(move |x| (x, y)))
+    }
+
+    /// Copy of a rectangle of the grid, clipped to it.
+    pub fn sub_grid(&self, (x, y): Position, width: usize, height: usize) -> Self
+    where
+        T: Clone,
+    {
+        let width = width.min(self.width.saturating_sub(x));
+        let height = height.min(self.height.saturating_sub(y));
+        Grid::from_fn(width, height, |(dx, dy)| self[(x + dx, y + dy)].clone())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width, and such grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Shifts row `y` right by `by` cells, the last cells wrapping around to
+    /// the start.
+    pub fn rotate_row(&mut self, y: usize, by: usize) {
+        assert!(y < self.height, "Row {} out of bounds", y);
+        if self.width > 0 {
+            let start = y * self.width;
+            self.cells[start..start + self.width].rotate_right(by % self.width);
+        }
+    }
+
+    /// Shifts column `x` down by `by` cells, the last cells wrapping around
+    /// to the top.
+    pub fn rotate_column(&mut self, x: usize, by: usize)
+    where
+        T: Clone,
+    {
+        assert!(x < self.width, "Column {} out of bounds", x);
+        let mut column: Vec<T> = (0..self.height).map(|y| self[(x, y)].clone()).collect();
+        if !column.is_empty() {
+            column.rotate_right(by % self.height);
+        }
+        for (y, value) in column.into_iter().enumerate() {
+            self[(x, y)] = value;
+        }
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    /// Draws the grid with one character per cell.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .take(self.height)
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().take(self.height).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |character| character.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let error = Grid::parse("12\n3x", |character| character.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: Unexpected character (at \"x\")"
+        );
+        let error = Grid::parse("12\n3", |character| character.to_digit(10)).unwrap_err();
+        assert_eq!(error.message, "Row is 1 cells wide instead of 2");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789");
+        let values = |positions: Vec<Position>| -> Vec<u32> {
+            positions
+                .into_iter()
+                .map(|position| grid[position])
+                .collect()
+        };
+        assert_eq!(values(grid.neighbours_4((0, 0)).collect()), [2, 4]);
+        assert_eq!(values(grid.neighbours_4((1, 1)).collect()), [2, 4, 6, 8]);
+        assert_eq!(values(grid.neighbours_8((2, 2)).collect()), [5, 6, 8]);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut grid = digits("123\n456\n789");
+        grid.rotate_row(0, 4);
+        assert_eq!(grid.to_string(), "312\n456\n789");
+        grid.rotate_column(1, 1);
+        assert_eq!(grid.to_string(), "382\n416\n759");
+    }
+
+    #[test]
+    fn test_rect() {
+        let mut grid = Grid::new(4, 3, false);
+        for position in grid.rect((2, 1), 5, 5) {
+            grid[position] = true;
+        }
+        assert_eq!(
+            grid.render(|&lit| if lit { '#' } else { '.' }),
+            "....\n..##\n..##"
+        );
+        let sub_grid = digits("123\n456\n789").sub_grid((1, 1), 5, 1);
+        assert_eq!(sub_grid.to_string(), "56");
+    }
+}
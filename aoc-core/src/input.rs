@@ -0,0 +1,100 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::Metadata;
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// The conventional input file of a day, next to its binary.
+    pub fn default_for(metadata: &Metadata) -> Self {
+        Source::File(default_path(metadata))
+    }
+
+    /// Reads the whole input, normalized with `normalize`.
+    pub fn load(&self) -> io::Result<String> {
+        let raw = match self {
+            Source::Stdin => {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw)?;
+                raw
+            }
+            Source::File(path) => fs::read_to_string(path)?,
+        };
+        Ok(normalize(&raw))
+    }
+}
+
+impl FromStr for Source {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "" => Err("Input path can't be empty".to_string()),
+            "-" => Ok(Source::Stdin),
+            path => Ok(Source::File(PathBuf::from(path))),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Turns CRLF line endings into LF and strips trailing newlines, so every
+/// parser sees the same shape of input regardless of where it came from.
+pub fn normalize(raw: &str) -> String {
+    raw.replace("\r\n", "\n").trim_end_matches('\n').to_string()
+}
+
+/// Root of the workspace, where each year's package lives.
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-core lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Conventional location of a day's puzzle input: `src/bin/dayNN/input.txt`
+/// inside the year's package.
+pub fn default_path(metadata: &Metadata) -> PathBuf {
+    workspace_root()
+        .join(format!("aoc-{}", metadata.year))
+        .join("src/bin")
+        .join(format!("day{:02}", metadata.day))
+        .join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n"), "1\n2");
+        assert_eq!(normalize("1\n2\n"), "1\n2");
+        assert_eq!(normalize("1\n\n2"), "1\n\n2");
+        assert_eq!(normalize("  1 "), "  1 ");
+    }
+
+    #[test]
+    fn test_source_from_str() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!("in.txt".parse(), Ok(Source::File(PathBuf::from("in.txt"))));
+        assert!("".parse::<Source>().is_err());
+    }
+}
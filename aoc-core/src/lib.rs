@@ -0,0 +1,167 @@
+use std::{env, fmt, process};
+
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod circuit;
+mod error;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod md5;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+pub mod subsets;
+pub mod tsp;
+pub mod vm;
+
+pub use answer::Answer;
+use answers::{Answers, Check};
+pub use error::AocError;
+use input::Source;
+
+/// Identifies a puzzle within the whole Advent of Code calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Metadata {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+}
+
+impl Metadata {
+    pub fn of<S: Solution>() -> Self {
+        Metadata {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+        }
+    }
+}
+
+impl fmt::Display for Metadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {:02}: {}", self.year, self.day, self.title)
+    }
+}
+
+/// A single day's puzzle.
+///
+/// The raw input is parsed once with `parse` and the result is shared by both
+/// parts, so expensive parsing isn't repeated. Bad inputs and failed solves
+/// are returned as an `AocError` rather than panicking, so that runners can
+/// report them and carry on with the other days.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Output1, AocError>;
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Output2, AocError>;
+}
+
+/// Object safe view of a `Solution`, used to handle days from different
+/// years (and with different input/output types) uniformly.
+pub trait Puzzle: Sync {
+    fn metadata(&self) -> Metadata;
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, AocError>;
+}
+
+/// A puzzle whose input has already been parsed.
+pub trait Prepared {
+    fn part_1(&self) -> Result<Answer, AocError>;
+    fn part_2(&self) -> Result<Answer, AocError>;
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<'a, S: Solution> Prepared for Parsed<'a, S> {
+    fn part_1(&self) -> Result<Answer, AocError> {
+        self.solution.part_1(&self.input).map(Into::into)
+    }
+
+    fn part_2(&self) -> Result<Answer, AocError> {
+        self.solution.part_2(&self.input).map(Into::into)
+    }
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn metadata(&self) -> Metadata {
+        Metadata::of::<S>()
+    }
+
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, AocError> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            input: self.parse(input)?,
+        }))
+    }
+}
+
+/// Loads the input given as first command line argument (a path, or `-` for
+/// stdin) falling back to the day's conventional input file, then solves both
+/// parts and prints their outputs.
+///
+/// When running on the conventional input, outputs are also checked against
+/// the year's known answers, and the process exits with an error if any of
+/// them is wrong or couldn't be computed.
+pub fn run<S: Solution>(solution: &S) {
+    let (source, answers) = match env::args().nth(1) {
+        Some(argument) => (
+            argument.parse().expect("Invalid input argument"),
+            Answers::default(),
+        ),
+        None => (
+            Source::default_for(&Metadata::of::<S>()),
+            Answers::load(S::YEAR).unwrap_or_else(|error| panic!("{}", error)),
+        ),
+    };
+    let input = source
+        .load()
+        .unwrap_or_else(|error| panic!("Unable to read input from {}: {}", source, error));
+    let input = solution.parse(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let report = |part: u8, output: Result<Answer, AocError>| {
+        let output = match output {
+            Ok(output) => output,
+            Err(error) => {
+                println!("Part {} failed with {}", part, error);
+                return false;
+            }
+        };
+        let check = Check::new(answers.get(S::DAY, part), &output);
+        // grids read better starting on their own line
+        let output = match output {
+            Answer::Grid(_) => format!("\n{}", output),
+            _ => output.to_string(),
+        };
+        match &check {
+            Check::Pass => println!("Part {} output is {} (correct)", part, output),
+            Check::Fail(expected) => {
+                println!("Part {} output is {} (expected {})", part, output, expected)
+            }
+            Check::Missing => println!("Part {} output is {}", part, output),
+        }
+        !matches!(check, Check::Fail(_))
+    };
+    let succeeded = [
+        report(1, solution.part_1(&input).map(Into::into)),
+        report(2, solution.part_2(&input).map(Into::into)),
+    ];
+    if succeeded.contains(&false) {
+        process::exit(1);
+    }
+}
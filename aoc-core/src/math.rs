@@ -0,0 +1,124 @@
+//! Number theory helpers: divisor sums for every number up to a limit,
+//! modular exponentiation, gcd/lcm and the Chinese remainder theorem.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, 0 if either number is.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// `base` to the power of `exponent` modulo `modulus`, by squaring.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "The modulus can't be 0");
+    // products of two residues may not fit in a u64
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// `(gcd, x, y)` such that `a·x + b·y = gcd`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - a / b * y)
+    }
+}
+
+/// The smallest `x` and the modulus `m` such that every `x + k·m` is
+/// congruent to each `(residue, modulus)` pair, or `None` if they contradict
+/// each other or the combined modulus doesn't fit in a `u64`. Moduli don't
+/// need to be coprime.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let mut solution: (i128, i128) = (0, 1);
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "The modulus can't be 0");
+        let (x, m) = solution;
+        let (residue, modulus) = (residue as i128 % modulus as i128, modulus as i128);
+        // x + m·k ≡ residue (mod modulus), so m·k ≡ residue - x (mod modulus)
+        let (gcd, inverse, _) = extended_gcd(m, modulus);
+        let difference = residue - x;
+        if difference % gcd != 0 {
+            return None;
+        }
+        let step = modulus / gcd;
+        let k = (difference / gcd % step)
+            .checked_mul(inverse % step)?
+            .rem_euclid(step);
+        let combined = m.checked_mul(step)?;
+        solution = (
+            m.checked_mul(k)?.checked_add(x)?.rem_euclid(combined),
+            combined,
+        );
+    }
+    let (x, m) = solution;
+    Some((u64::try_from(x).ok()?, u64::try_from(m).ok()?))
+}
+
+/// Sum of the divisors of every number below `limit`, indexed by the number
+/// (0 for 0).
+pub fn divisor_sums(limit: usize) -> Vec<u64> {
+    divisor_sums_capped(limit, usize::MAX)
+}
+
+/// Like [`divisor_sums`], but each divisor only counts for its first
+/// `multiples` multiples: with 2 multiples, 3 adds to 3 and 6 but not to 9.
+pub fn divisor_sums_capped(limit: usize, multiples: usize) -> Vec<u64> {
+    let mut sums = vec![0; limit];
+    for divisor in 1..limit {
+        for number in (divisor..limit).step_by(divisor).take(multiples) {
+            sums[number] += divisor as u64;
+        }
+    }
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_pow() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        let big = (1 << 61) - 1;
+        assert_eq!(crt(&[(1, big), (2, big - 2), (3, big - 4)]), None);
+    }
+
+    #[test]
+    fn test_divisor_sums() {
+        assert_eq!(divisor_sums(10), [0, 1, 3, 4, 7, 6, 12, 8, 15, 13]);
+        assert_eq!(divisor_sums_capped(10, 2), [0, 1, 3, 3, 6, 5, 9, 7, 12, 9]);
+    }
+}
@@ -0,0 +1,186 @@
+//! Searches for MD5 digests of `prefix + index` starting with zeros, the
+//! proof of work behind several puzzles.
+//!
+//! ```ignore
+//! let (index, digest) = HashSearch::new(secret, 5).starting_at(1).matches().next()?;
+//! ```
+
+use std::{collections::VecDeque, num::NonZeroUsize, thread};
+
+use crypto::{digest::Digest as _, md5::Md5};
+
+pub type Digest = [u8; 16];
+
+/// Indices each thread hashes before the matches are merged.
+const CHUNK: usize = 20_000;
+
+/// Hex digit `index` of `digest`, 0 being the most significant one.
+pub fn nibble(digest: &Digest, index: usize) -> u8 {
+    let byte = digest[index / 2];
+    if index.is_multiple_of(2) {
+        byte >> 4
+    } else {
+        byte & 0x0F
+    }
+}
+
+/// Number of leading zero hex digits of `digest`.
+pub fn leading_zeros(digest: &Digest) -> usize {
+    (0..32)
+        .take_while(|&index| nibble(digest, index) == 0)
+        .count()
+}
+
+/// Whether the first `zeros` hex digits of `digest` are all 0, without
+/// formatting it.
+fn starts_with_zeros(digest: &Digest, zeros: usize) -> bool {
+    digest[..zeros / 2].iter().all(|&byte| byte == 0)
+        && (zeros.is_multiple_of(2) || digest[zeros / 2] >> 4 == 0)
+}
+
+/// The indices, in increasing order, whose digest starts with `zeros` hex 0s.
+pub struct HashSearch {
+    /// Hasher that already consumed the prefix, cloned for every index.
+    prefix: Md5,
+    zeros: usize,
+    start: usize,
+    threads: usize,
+}
+
+impl HashSearch {
+    pub fn new(prefix: &str, zeros: usize) -> Self {
+        assert!(zeros <= 32, "A digest only has 32 hex digits");
+        let mut hasher = Md5::new();
+        hasher.input_str(prefix);
+        HashSearch {
+            prefix: hasher,
+            zeros,
+            start: 0,
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+
+    /// Skips the indices before `start`.
+    pub fn starting_at(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Hashes on `threads` threads instead of one per available core.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    fn digest(&self, index: usize) -> Digest {
+        let mut hasher = self.prefix;
+        hasher.input(write_decimal(index, &mut [0; 20]));
+        let mut digest = [0; 16];
+        hasher.result(&mut digest);
+        digest
+    }
+
+    /// Matches among `count` indices from `start`, in order.
+    fn search(&self, start: usize, count: usize) -> Vec<(usize, Digest)> {
+        (start..start + count)
+            .map(|index| (index, self.digest(index)))
+            .filter(|(_, digest)| starts_with_zeros(digest, self.zeros))
+            .collect()
+    }
+
+    /// Every match, lazily and in order. The search never ends by itself, so
+    /// it's up to the caller to stop once it has found what it needs.
+    pub fn matches(self) -> Matches {
+        Matches {
+            next: self.start,
+            search: self,
+            found: VecDeque::new(),
+        }
+    }
+}
+
+/// Iterator over the matches of a [`HashSearch`], which hashes the next
+/// range of indices across every thread whenever it runs out of matches.
+pub struct Matches {
+    search: HashSearch,
+    next: usize,
+    found: VecDeque<(usize, Digest)>,
+}
+
+impl Iterator for Matches {
+    type Item = (usize, Digest);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let search = &self.search;
+        let found = &mut self.found;
+        let next = &mut self.next;
+        while found.is_empty() {
+            let start = *next;
+            *next += search.threads * CHUNK;
+            thread::scope(|scope| {
+                let workers: Vec<_> = (0..search.threads)
+                    .map(|worker| scope.spawn(move || search.search(start + worker * CHUNK, CHUNK)))
+                    .collect();
+                // chunks are joined in order, keeping the matches sorted
+                for worker in workers {
+                    found.extend(worker.join().expect("Hashing threads don't panic"));
+                }
+            });
+        }
+        found.pop_front()
+    }
+}
+
+/// Writes `value` in decimal at the end of `buffer`, returning the digits.
+fn write_decimal(mut value: usize, buffer: &mut [u8; 20]) -> &[u8] {
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            return &buffer[start..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(write_decimal(0, &mut [0; 20]), b"0");
+        assert_eq!(write_decimal(609043, &mut [0; 20]), b"609043");
+        assert_eq!(
+            write_decimal(usize::MAX, &mut [0; 20]),
+            usize::MAX.to_string().as_bytes()
+        );
+
+        let digest = HashSearch::new("abcdef", 0).digest(609043);
+        assert_eq!(leading_zeros(&digest), 5);
+        assert_eq!((nibble(&digest, 5), nibble(&digest, 6)), (1, 13));
+        assert!(starts_with_zeros(&digest, 5));
+        assert!(!starts_with_zeros(&digest, 6));
+    }
+
+    #[test]
+    fn test_matches() {
+        let first = HashSearch::new("abcdef", 5).starting_at(1).matches().next();
+        assert_eq!(first.map(|(index, _)| index), Some(609043));
+
+        let single: Vec<_> = HashSearch::new("abc", 2)
+            .with_threads(1)
+            .matches()
+            .take(300)
+            .collect();
+        let parallel: Vec<_> = HashSearch::new("abc", 2)
+            .with_threads(3)
+            .matches()
+            .take(300)
+            .collect();
+        assert_eq!(single, parallel);
+        assert!(single.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(single.iter().all(|(_, digest)| leading_zeros(digest) >= 2));
+    }
+}
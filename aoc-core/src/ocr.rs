@@ -0,0 +1,237 @@
+//! Reads the block letters some puzzles draw on a screen instead of giving a
+//! plain answer.
+
+/// A fixed height font, letters laid out every `pitch` columns.
+struct Font {
+    height: usize,
+    pitch: usize,
+    glyphs: &'static [(char, &'static str)],
+}
+
+/// 4x6 letters, e.g. 2016 day 08 and 2021 day 13.
+const SMALL: Font = Font {
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+        ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+        ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+        ('E', "####\n#...\n###.\n#...\n#...\n####"),
+        ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+        ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+        ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+        ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+        ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+        ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+        ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+        ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+        ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+        ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+        ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+        // the only letter using the spacing column
+        ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+        ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+    ],
+};
+
+/// 6x10 letters, e.g. 2018 day 10.
+const LARGE: Font = Font {
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'B',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+        ),
+        (
+            'C',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+        ),
+        (
+            'E',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'F',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'G',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+        ),
+        (
+            'H',
+            "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'J',
+            "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+        ),
+        (
+            'K',
+            "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+        ),
+        (
+            'L',
+            "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'N',
+            "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+        ),
+        (
+            'P',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'R',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+        ),
+        (
+            'X',
+            "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+        ),
+        (
+            'Z',
+            "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+        ),
+    ],
+};
+
+impl Font {
+    fn recognize(&self, glyph: &[String]) -> Option<char> {
+        self.glyphs.iter().find_map(|(letter, drawing)| {
+            let matches = drawing
+                .lines()
+                .zip(glyph)
+                .all(|(expected, row)| row.trim_end_matches('.') == expected.trim_end_matches('.'));
+            matches.then_some(*letter)
+        })
+    }
+}
+
+/// Reads the letters drawn by the lit pixels of `rows`, in either font,
+/// picked from the height of the drawing. Blank rows around the letters are
+/// ignored, but the first letter must start at the first column, as some
+/// letters (like `I`) have a blank first column.
+///
+/// Fails with a drawing of every glyph that isn't a known letter.
+pub fn read<R: AsRef<[bool]>>(rows: &[R]) -> Result<String, String> {
+    let lit = |row: &R| row.as_ref().iter().any(|&pixel| pixel);
+    let (Some(top), Some(bottom)) = (rows.iter().position(lit), rows.iter().rposition(lit)) else {
+        return Err("Nothing to read, every pixel is off".to_string());
+    };
+    let rows: Vec<&[bool]> = rows[top..=bottom].iter().map(AsRef::as_ref).collect();
+    let font = [&SMALL, &LARGE]
+        .into_iter()
+        .find(|font| font.height == rows.len())
+        .ok_or_else(|| format!("No font is {} pixels high", rows.len()))?;
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+    let column_lit = |column: usize| rows.iter().any(|row| row.get(column) == Some(&true));
+    let right = (0..width).rfind(|&column| column_lit(column)).unwrap_or(0);
+
+    let mut text = String::new();
+    let mut unknown = vec![];
+    for (index, start) in (0..=right).step_by(font.pitch).enumerate() {
+        let glyph: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                (start..start + font.pitch)
+                    .map(|column| match row.get(column) {
+                        Some(true) => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        match font.recognize(&glyph) {
+            Some(letter) => text.push(letter),
+            None => unknown.push(format!("letter {}:\n{}", index + 1, glyph.join("\n"))),
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(format!(
+            "Unknown letters in the drawing\n{}",
+            unknown.join("\n")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lays out `text` the way a puzzle would draw it.
+    fn draw(font: &Font, text: &str) -> Vec<Vec<bool>> {
+        let mut rows = vec![vec![false; text.len() * font.pitch]; font.height];
+        for (index, letter) in text.chars().enumerate() {
+            let (_, drawing) = font
+                .glyphs
+                .iter()
+                .find(|(glyph, _)| *glyph == letter)
+                .unwrap();
+            for (y, line) in drawing.lines().enumerate() {
+                for (x, pixel) in line.chars().enumerate() {
+                    rows[y][index * font.pitch + x] = pixel == '#';
+                }
+            }
+        }
+        rows
+    }
+
+    fn parse(drawing: &str) -> Vec<Vec<bool>> {
+        drawing
+            .lines()
+            .map(|line| line.chars().map(|pixel| pixel == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_read_small() {
+        assert_eq!(read(&draw(&SMALL, "HZLEHJRK")), Ok("HZLEHJRK".to_string()));
+        let every_letter: String = SMALL.glyphs.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(read(&draw(&SMALL, &every_letter)), Ok(every_letter));
+
+        // Y runs into the letter after it
+        let screen = parse(
+            "####.####.####.#...##..#.####.###..####..###...##.\n\
+             #....#....#....#...##.#..#....#..#.#......#.....#.\n\
+             ###..###..###...#.#.##...###..#..#.###....#.....#.\n\
+             #....#....#......#..#.#..#....###..#......#.....#.\n\
+             #....#....#......#..#.#..#....#.#..#......#..#..#.\n\
+             ####.#....####...#..#..#.#....#..#.#.....###..##..",
+        );
+        assert_eq!(read(&screen), Ok("EFEYKFRFIJ".to_string()));
+    }
+
+    #[test]
+    fn test_read_large() {
+        let every_letter: String = LARGE.glyphs.iter().map(|(letter, _)| letter).collect();
+        let mut rows = draw(&LARGE, &every_letter);
+        rows.insert(0, vec![false; 3]);
+        rows.push(vec![]);
+        assert_eq!(read(&rows), Ok(every_letter));
+        assert_eq!(read(&draw(&SMALL, "IS")), Ok("IS".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_letters() {
+        let mut rows = draw(&SMALL, "ABC");
+        rows[0][5] = false;
+        let error = read(&rows).unwrap_err();
+        assert!(error.contains("letter 2:\n.##..\n#..#."), "{}", error);
+        assert!(!error.contains("letter 1"), "{}", error);
+
+        assert!(read(&[[false; 4]; 6]).is_err());
+        assert!(read(&[[true; 4]; 7]).unwrap_err().contains("7 pixels"));
+    }
+}
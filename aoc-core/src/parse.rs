@@ -0,0 +1,296 @@
+//! Helpers to parse puzzle inputs, failing with the line, column and text
+//! that couldn't be parsed instead of a bare panic.
+
+use std::{error, fmt, str::FromStr};
+
+use regex::Regex;
+
+/// Where and why an input couldn't be parsed. Lines and columns start at 1.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (at {:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+/// Same as `Display`, so that unwrapping a parse shows where it failed.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A line of the input, knowing its number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error about `part`, which should be a slice of the line so that the
+    /// column points at it; the column is 1 otherwise.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        let line_range = self.text.as_bytes().as_ptr_range();
+        let part_range = part.as_bytes().as_ptr_range();
+        let column = if line_range.start <= part_range.start && part_range.end <= line_range.end {
+            let offset = part_range.start as usize - line_range.start as usize;
+            self.text
+                .get(..offset)
+                .map_or(1, |before| before.chars().count() + 1)
+        } else {
+            1
+        };
+        ParseError {
+            line: self.number,
+            column,
+            text: part.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Parses `part` of the line, see [`error`](Self::error).
+    pub fn parse<T>(&self, part: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.parse()
+            .map_err(|error| self.error(part, format!("{}", error)))
+    }
+
+    /// Whitespace separated words.
+    pub fn words(&self) -> impl Iterator<Item = &'a str> {
+        self.text.split_whitespace()
+    }
+
+    pub fn word(&self, index: usize) -> Result<&'a str, ParseError> {
+        self.words().nth(index).ok_or_else(|| {
+            self.error(
+                &self.text[self.text.len()..],
+                format!("Missing word {}", index + 1),
+            )
+        })
+    }
+
+    /// Parses the word at `index`.
+    pub fn field<T>(&self, index: usize) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.parse(self.word(index)?)
+    }
+
+    /// Every integer in the line, in order, whatever separates them. A `-`
+    /// right before digits is a sign unless it follows a digit, so `1-3`
+    /// is `1` and `3` while `x=-3` is `-3`.
+    pub fn integers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut integers = vec![];
+        let mut index = 0;
+        while index < bytes.len() {
+            let negative = bytes[index] == b'-'
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+                && (index == 0 || !bytes[index - 1].is_ascii_digit());
+            if !negative && !bytes[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            index += 1;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            integers.push(self.parse(&self.text[start..index])?);
+        }
+        Ok(integers)
+    }
+
+    /// Exactly `N` integers, see [`integers`](Self::integers).
+    pub fn integers_n<T, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let integers = self.integers()?;
+        let count = integers.len();
+        integers.try_into().map_err(|_| {
+            self.error(
+                self.text,
+                format!("Expected {} integers, found {}", N, count),
+            )
+        })
+    }
+
+    /// Matches the whole line against `regex`.
+    pub fn captures(&self, regex: &Regex) -> Result<Captures<'a>, ParseError> {
+        let captures = regex
+            .captures(self.text)
+            .ok_or_else(|| self.error(self.text, format!("Doesn't match {}", regex)))?;
+        Ok(Captures {
+            line: *self,
+            captures,
+        })
+    }
+}
+
+/// Groups of a line matched by a regex, parsed with the position of the
+/// group on errors.
+pub struct Captures<'a> {
+    line: Line<'a>,
+    captures: regex::Captures<'a>,
+}
+
+impl<'a> Captures<'a> {
+    /// Text of a group, which fails when it didn't participate in the match.
+    pub fn str(&self, group: usize) -> Result<&'a str, ParseError> {
+        self.captures
+            .get(group)
+            .map(|capture| capture.as_str())
+            .ok_or_else(|| {
+                self.line
+                    .error(self.line.text, format!("Group {} didn't match", group))
+            })
+    }
+
+    pub fn get<T>(&self, group: usize) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.line.parse(self.str(group)?)
+    }
+}
+
+/// Numbered lines of `input`.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+/// Parses every line with `parse`, stopping at the first error.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    parse: impl FnMut(Line<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    lines(input).map(parse).collect()
+}
+
+/// Groups of lines separated by blank lines, keeping their numbers.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![];
+    let mut section = vec![];
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !section.is_empty() {
+                sections.push(section);
+                section = vec![];
+            }
+        } else {
+            section.push(line);
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 3, text }
+    }
+
+    #[test]
+    fn test_fields() {
+        let line = line("London to Dublin = 464");
+        assert_eq!(line.word(2), Ok("Dublin"));
+        assert_eq!(line.field::<u32>(4), Ok(464));
+
+        let error = line.field::<u32>(0).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "London");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid digit found in string (at \"London\")"
+        );
+
+        let error = line.word(5).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (23, "Missing word 6")
+        );
+    }
+
+    #[test]
+    fn test_error_columns() {
+        let text = "é = x + y";
+        let line = line(&text[..6]);
+        assert_eq!(line.error(&text[5..6], "Expected x").column, 5);
+        // parts that aren't inside the line can't be pointed at
+        assert_eq!(line.error(&text[9..], "Expected y").column, 1);
+        assert_eq!(line.error(&text[4..7], "Expected x").column, 1);
+        assert_eq!(line.error("x", "Expected x").column, 1);
+    }
+
+    #[test]
+    fn test_integers() {
+        let integers = line("target area: x=20..30, y=-10..-5").integers::<i32>();
+        assert_eq!(integers, Ok(vec![20, 30, -10, -5]));
+        assert_eq!(line("1-3 a: abc").integers::<u8>(), Ok(vec![1, 3]));
+        assert_eq!(line("no numbers").integers::<u8>(), Ok(vec![]));
+
+        let error = line("3 -> 300").integers::<u8>().unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (6, "300"));
+
+        assert_eq!(line("6,10").integers_n::<usize, 2>(), Ok([6, 10]));
+        let error = line("6,10,2").integers_n::<usize, 2>().unwrap_err();
+        assert_eq!(error.message, "Expected 2 integers, found 3");
+    }
+
+    #[test]
+    fn test_captures() {
+        let regex = Regex::new(r"^fold along ([xy])=(\d+)$").unwrap();
+        let captures = line("fold along y=7").captures(&regex).unwrap();
+        assert_eq!(captures.str(1), Ok("y"));
+        assert_eq!(captures.get::<usize>(2), Ok(7));
+
+        let error = line("fold along z=7").captures(&regex).err().unwrap();
+        assert_eq!(error.column, 1);
+        let error = captures.get::<i8>(1).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (12, "y"));
+    }
+
+    #[test]
+    fn test_sections() {
+        let sections = sections("7,4,9\n\n22 13\n 8  2\n\n\n3 15\n");
+        let numbers: Vec<Vec<usize>> = sections
+            .iter()
+            .map(|section| section.iter().map(|line| line.number).collect())
+            .collect();
+        assert_eq!(numbers, [vec![1], vec![3, 4], vec![7]]);
+    }
+}
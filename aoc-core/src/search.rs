@@ -0,0 +1,450 @@
+//! Graph searches over any puzzle implementing [`SearchSpace`].
+//!
+//! ```ignore
+//! let path = Search::new(&maze).astar(start)?;
+//! println!("{} steps: {:?}", path.cost, path.states);
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The states of a puzzle and the moves between them.
+pub trait SearchSpace {
+    type State: Clone + Eq + Hash;
+
+    /// States reachable in one move from `state`.
+    fn neighbours(&self, state: &Self::State) -> impl IntoIterator<Item = Self::State>;
+
+    /// Cost of the move from `from` to its neighbour `to`.
+    fn cost(&self, _from: &Self::State, _to: &Self::State) -> u64 {
+        1
+    }
+
+    /// Neighbours of `state` along with the cost of moving to each. Spaces
+    /// that only know a move's cost while making it, rather than from both
+    /// ends, override this instead of [`cost`](Self::cost).
+    fn moves(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, u64)> {
+        self.neighbours(state).into_iter().map(|next| {
+            let cost = self.cost(state, &next);
+            (next, cost)
+        })
+    }
+
+    /// Lower bound of the cost from `state` to the closest goal, used by A*.
+    /// It must never overestimate, or A* may return a costlier path.
+    fn heuristic(&self, _state: &Self::State) -> u64 {
+        0
+    }
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+}
+
+/// States from the start to a goal, both included, and the total cost of
+/// the moves between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+impl<S> Path<S> {
+    pub fn goal(&self) -> &S {
+        self.states
+            .last()
+            .expect("Paths have at least the start state")
+    }
+}
+
+/// A search over `space`, which skips states it has already reached unless
+/// told otherwise.
+pub struct Search<'a, S> {
+    space: &'a S,
+    dedup: bool,
+    buckets: bool,
+}
+
+struct Node<T> {
+    state: T,
+    parent: Option<usize>,
+    cost: u64,
+}
+
+/// Every state reached so far, each node pointing back to the one it was
+/// reached from.
+struct Tree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T: Clone> Tree<T> {
+    fn push(&mut self, state: T, parent: Option<usize>, cost: u64) -> usize {
+        self.nodes.push(Node {
+            state,
+            parent,
+            cost,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, mut index: usize) -> Path<T> {
+        let cost = self.nodes[index].cost;
+        let mut states = vec![];
+        loop {
+            let node = &self.nodes[index];
+            states.push(node.state.clone());
+            match node.parent {
+                Some(parent) => index = parent,
+                None => break,
+            }
+        }
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+/// Nodes to expand, cheapest first.
+enum Frontier {
+    Heap(BinaryHeap<Reverse<(u64, usize)>>),
+    /// One bucket per priority. Expansion never goes back to a cheaper
+    /// bucket, which holds as long as the heuristic is consistent.
+    Buckets {
+        buckets: Vec<Vec<usize>>,
+        current: usize,
+    },
+}
+
+impl Frontier {
+    fn push(&mut self, priority: u64, node: usize) {
+        match self {
+            Frontier::Heap(heap) => heap.push(Reverse((priority, node))),
+            Frontier::Buckets { buckets, current } => {
+                let priority = (priority as usize).max(*current);
+                if buckets.len() <= priority {
+                    buckets.resize_with(priority + 1, Vec::new);
+                }
+                buckets[priority].push(node);
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        match self {
+            Frontier::Heap(heap) => heap.pop().map(|Reverse((_, node))| node),
+            Frontier::Buckets { buckets, current } => {
+                while *current < buckets.len() {
+                    if let Some(node) = buckets[*current].pop() {
+                        return Some(node);
+                    }
+                    *current += 1;
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<'a, S: SearchSpace> Search<'a, S> {
+    pub fn new(space: &'a S) -> Self {
+        Search {
+            space,
+            dedup: true,
+            buckets: false,
+        }
+    }
+
+    /// Expands states again every time they're reached. Only for spaces that
+    /// can't loop back, like those whose states carry their own history.
+    pub fn without_dedup(mut self) -> Self {
+        self.dedup = false;
+        self
+    }
+
+    /// Keeps Dijkstra's and A*'s frontier in one bucket per cost instead of
+    /// a heap, faster when costs are small integers.
+    pub fn with_buckets(mut self) -> Self {
+        self.buckets = true;
+        self
+    }
+
+    /// Path with the fewest moves, whatever they cost.
+    pub fn bfs(&self, start: S::State) -> Option<Path<S::State>> {
+        let mut tree = Tree { nodes: vec![] };
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        if self.dedup {
+            seen.insert(start.clone());
+        }
+        queue.push_back(tree.push(start, None, 0));
+
+        while let Some(index) = queue.pop_front() {
+            if self.space.is_goal(&tree.nodes[index].state) {
+                return Some(tree.path(index));
+            }
+            let state = tree.nodes[index].state.clone();
+            for (next, step) in self.space.moves(&state) {
+                if self.dedup && !seen.insert(next.clone()) {
+                    continue;
+                }
+                let cost = tree.nodes[index].cost + step;
+                queue.push_back(tree.push(next, Some(index), cost));
+            }
+        }
+        None
+    }
+
+    /// Any path to a goal, going as deep as possible first.
+    pub fn dfs(&self, start: S::State) -> Option<Path<S::State>> {
+        let mut tree = Tree { nodes: vec![] };
+        let mut seen = HashSet::new();
+        let mut stack = vec![tree.push(start, None, 0)];
+
+        while let Some(index) = stack.pop() {
+            let state = tree.nodes[index].state.clone();
+            if self.dedup && !seen.insert(state.clone()) {
+                continue;
+            }
+            if self.space.is_goal(&state) {
+                return Some(tree.path(index));
+            }
+            for (next, step) in self.space.moves(&state) {
+                if self.dedup && seen.contains(&next) {
+                    continue;
+                }
+                let cost = tree.nodes[index].cost + step;
+                stack.push(tree.push(next, Some(index), cost));
+            }
+        }
+        None
+    }
+
+    /// Number of goals reached, counting every path to them when
+    /// [`without_dedup`](Self::without_dedup). Goals aren't expanded.
+    pub fn count_goals(&self, start: S::State) -> usize {
+        let mut count = 0;
+        let mut seen = HashSet::new();
+        let mut stack = vec![start];
+
+        while let Some(state) = stack.pop() {
+            if self.dedup && !seen.insert(state.clone()) {
+                continue;
+            }
+            if self.space.is_goal(&state) {
+                count += 1;
+                continue;
+            }
+            stack.extend(
+                self.space
+                    .neighbours(&state)
+                    .into_iter()
+                    .filter(|next| !self.dedup || !seen.contains(next)),
+            );
+        }
+        count
+    }
+
+    /// Cheapest path, ignoring the heuristic.
+    pub fn dijkstra(&self, start: S::State) -> Option<Path<S::State>> {
+        self.best_first(start, |_| 0)
+    }
+
+    /// Cheapest path, expanding first the states the heuristic deems closer
+    /// to a goal.
+    pub fn astar(&self, start: S::State) -> Option<Path<S::State>> {
+        self.best_first(start, |state| self.space.heuristic(state))
+    }
+
+    fn best_first(
+        &self,
+        start: S::State,
+        heuristic: impl Fn(&S::State) -> u64,
+    ) -> Option<Path<S::State>> {
+        let mut tree = Tree { nodes: vec![] };
+        let mut best: HashMap<S::State, u64> = HashMap::new();
+        let mut frontier = if self.buckets {
+            Frontier::Buckets {
+                buckets: vec![],
+                current: 0,
+            }
+        } else {
+            Frontier::Heap(BinaryHeap::new())
+        };
+        if self.dedup {
+            best.insert(start.clone(), 0);
+        }
+        frontier.push(heuristic(&start), tree.push(start, None, 0));
+
+        while let Some(index) = frontier.pop() {
+            let node = &tree.nodes[index];
+            // a cheaper way to this state was found after this one was queued
+            if self.dedup && best.get(&node.state).is_some_and(|&cost| cost < node.cost) {
+                continue;
+            }
+            if self.space.is_goal(&node.state) {
+                return Some(tree.path(index));
+            }
+            let (state, cost) = (node.state.clone(), node.cost);
+            for (next, step) in self.space.moves(&state) {
+                let cost = cost + step;
+                if self.dedup {
+                    match best.entry(next.clone()) {
+                        Entry::Occupied(mut entry) if *entry.get() > cost => {
+                            entry.insert(cost);
+                        }
+                        Entry::Occupied(_) => continue,
+                        Entry::Vacant(entry) => {
+                            entry.insert(cost);
+                        }
+                    }
+                }
+                let priority = cost + heuristic(&next);
+                frontier.push(priority, tree.push(next, Some(index), cost));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    /// Moves between the open cells of a maze, costing the digit of the
+    /// cell moved to.
+    struct Maze {
+        grid: Grid<Option<u64>>,
+        goal: Position,
+    }
+
+    impl SearchSpace for Maze {
+        type State = Position;
+
+        fn neighbours(&self, &position: &Position) -> impl IntoIterator<Item = Position> {
+            self.grid
+                .neighbours_4(position)
+                .filter(|&next| self.grid[next].is_some())
+                .collect::<Vec<_>>()
+        }
+
+        fn cost(&self, _: &Position, &to: &Position) -> u64 {
+            self.grid[to].unwrap()
+        }
+
+        fn heuristic(&self, &(x, y): &Position) -> u64 {
+            (x.abs_diff(self.goal.0) + y.abs_diff(self.goal.1)) as u64
+        }
+
+        fn is_goal(&self, position: &Position) -> bool {
+            *position == self.goal
+        }
+    }
+
+    fn maze(drawing: &str) -> Maze {
+        let grid = Grid::parse(drawing, |cell| match cell {
+            '#' => Some(None),
+            _ => cell.to_digit(10).map(|cost| Some(cost as u64)),
+        })
+        .unwrap();
+        let goal = (grid.width() - 1, grid.height() - 1);
+        Maze { grid, goal }
+    }
+
+    const MAZE: &str = "\
+        1191\n\
+        1#11\n\
+        1111\n\
+        9#91";
+
+    #[test]
+    fn test_shortest_paths() {
+        let maze = maze(MAZE);
+        let search = Search::new(&maze);
+
+        let fewest_moves = search.bfs((0, 0)).unwrap();
+        assert_eq!(fewest_moves.states.len(), 7);
+        assert_eq!(*fewest_moves.goal(), (3, 3));
+
+        let cheapest = [
+            search.dijkstra((0, 0)),
+            search.astar((0, 0)),
+            Search::new(&maze).with_buckets().astar((0, 0)),
+        ];
+        for path in cheapest {
+            let path = path.unwrap();
+            assert_eq!(path.cost, 6);
+            assert_eq!(
+                path.states,
+                [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2), (3, 3)]
+            );
+        }
+
+        let path = search.dfs((0, 0)).unwrap();
+        assert_eq!((path.states[0], *path.goal()), ((0, 0), (3, 3)));
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let maze = maze("11\n##\n11");
+        let search = Search::new(&maze);
+        assert_eq!(search.bfs((0, 0)), None);
+        assert_eq!(search.dfs((0, 0)), None);
+        assert_eq!(search.with_buckets().dijkstra((0, 0)), None);
+    }
+
+    /// Paths through a grid only moving right or down.
+    struct Lattice(usize);
+
+    impl SearchSpace for Lattice {
+        type State = Position;
+
+        fn neighbours(&self, &(x, y): &Position) -> impl IntoIterator<Item = Position> {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x <= self.0 && y <= self.0)
+        }
+
+        fn is_goal(&self, position: &Position) -> bool {
+            *position == (self.0, self.0)
+        }
+    }
+
+    #[test]
+    fn test_count_goals() {
+        assert_eq!(Search::new(&Lattice(3)).count_goals((0, 0)), 1);
+        assert_eq!(
+            Search::new(&Lattice(3)).without_dedup().count_goals((0, 0)),
+            20
+        );
+    }
+
+    /// Counting up to a target by adding 1 for 1, or doubling for 2.
+    struct Counter(u64);
+
+    impl SearchSpace for Counter {
+        type State = u64;
+
+        fn neighbours(&self, number: &u64) -> impl IntoIterator<Item = u64> {
+            self.moves(number).into_iter().map(|(next, _)| next)
+        }
+
+        fn moves(&self, &number: &u64) -> impl IntoIterator<Item = (u64, u64)> {
+            [(number + 1, 1), (number * 2, 2)]
+                .into_iter()
+                .filter(|&(next, _)| next <= self.0)
+        }
+
+        fn is_goal(&self, &number: &u64) -> bool {
+            number == self.0
+        }
+    }
+
+    #[test]
+    fn test_moves() {
+        let path = Search::new(&Counter(10)).dijkstra(0).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states, [0, 1, 2, 4, 5, 10]);
+        assert_eq!(Search::new(&Counter(10)).bfs(0).unwrap().cost, 7);
+    }
+}
@@ -0,0 +1,194 @@
+//! Subsets of items whose sizes add up to a target, and splits of items into
+//! equal groups. Items are told apart by their index, so equal sizes still
+//! make different subsets.
+
+/// Number of subsets of each size (the index) whose items add up to
+/// `target`, by dynamic programming over the sums instead of enumerating.
+pub fn count_by_size(items: &[u64], target: u64) -> Vec<u64> {
+    let target = target as usize;
+    // ways[size][sum], only growing sizes as items are added
+    let mut ways = vec![vec![0u64; target + 1]; items.len() + 1];
+    ways[0][0] = 1;
+    for (added, &item) in items.iter().enumerate() {
+        let item = item as usize;
+        if item > target {
+            continue;
+        }
+        for size in (1..=added + 1).rev() {
+            for sum in (item..=target).rev() {
+                ways[size][sum] += ways[size - 1][sum - item];
+            }
+        }
+    }
+    ways.iter().map(|by_sum| by_sum[target]).collect()
+}
+
+/// Number of subsets whose items add up to `target`.
+pub fn count(items: &[u64], target: u64) -> u64 {
+    count_by_size(items, target).iter().sum()
+}
+
+/// Indices of every subset of `size` items adding up to `target`, in
+/// lexicographic order.
+pub fn of_size(items: &[u64], size: usize, target: u64) -> Vec<Vec<usize>> {
+    fn extend(
+        items: &[u64],
+        size: usize,
+        target: u64,
+        from: usize,
+        subset: &mut Vec<usize>,
+        subsets: &mut Vec<Vec<usize>>,
+    ) {
+        if subset.len() == size {
+            if target == 0 {
+                subsets.push(subset.clone());
+            }
+            return;
+        }
+        let missing = size - subset.len();
+        for index in from..=items.len().saturating_sub(missing) {
+            if items[index] > target {
+                continue;
+            }
+            subset.push(index);
+            extend(
+                items,
+                size,
+                target - items[index],
+                index + 1,
+                subset,
+                subsets,
+            );
+            subset.pop();
+        }
+    }
+
+    let mut subsets = vec![];
+    if size <= items.len() {
+        extend(items, size, target, 0, &mut vec![], &mut subsets);
+    }
+    subsets
+}
+
+/// Indices of every subset adding up to `target`, smallest subsets first.
+/// Each size is only searched once the smaller ones are exhausted.
+pub fn by_size(items: &[u64], target: u64) -> impl Iterator<Item = Vec<usize>> + '_ {
+    (0..=items.len()).flat_map(move |size| of_size(items, size, target))
+}
+
+/// Whether `items` can be split into `groups` groups adding up to the same
+/// sum.
+pub fn can_partition(items: &[u64], groups: usize) -> bool {
+    fn fill(items: &[u64], sums: &mut [u64], target: u64) -> bool {
+        let Some((&item, rest)) = items.split_first() else {
+            return true;
+        };
+        for group in 0..sums.len() {
+            // groups with the same sum are interchangeable
+            if sums[..group].contains(&sums[group]) || sums[group] + item > target {
+                continue;
+            }
+            sums[group] += item;
+            if fill(rest, sums, target) {
+                return true;
+            }
+            sums[group] -= item;
+        }
+        false
+    }
+
+    let total: u64 = items.iter().sum();
+    if groups == 0 {
+        return items.is_empty();
+    }
+    if !total.is_multiple_of(groups as u64) {
+        return false;
+    }
+    // placing the biggest items first prunes sooner
+    let mut items = items.to_vec();
+    items.sort_unstable_by(|a, b| b.cmp(a));
+    fill(&items, &mut vec![0; groups], total / groups as u64)
+}
+
+/// Every way to split `total` into `parts` ordered amounts, zeros included.
+pub fn compositions(total: u64, parts: usize) -> impl Iterator<Item = Vec<u64>> {
+    let mut next = (parts > 0).then(|| {
+        let mut first = vec![0; parts];
+        first[parts - 1] = total;
+        first
+    });
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+        // the next one in lexicographic order grows the rightmost amount that
+        // has units after it by one, and gives all but one of them to the
+        // last amount
+        let last = parts - 1;
+        let mut rest = 0;
+        for index in (0..last).rev() {
+            rest += current[index + 1];
+            if rest > 0 {
+                let mut following = current.clone();
+                following[index] += 1;
+                following[index + 1..].fill(0);
+                following[last] = rest - 1;
+                next = Some(following);
+                break;
+            }
+        }
+        Some(current)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTAINERS: [u64; 5] = [20, 15, 10, 5, 5];
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count(&CONTAINERS, 25), 4);
+        assert_eq!(count_by_size(&CONTAINERS, 25), [0, 0, 3, 1, 0, 0]);
+        assert_eq!(count(&CONTAINERS, 0), 1);
+        assert_eq!(count(&CONTAINERS, 100), 0);
+    }
+
+    #[test]
+    fn test_enumerate() {
+        assert_eq!(of_size(&CONTAINERS, 2, 25), [[0, 3], [0, 4], [1, 2]]);
+        assert_eq!(of_size(&CONTAINERS, 6, 25), Vec::<Vec<usize>>::new());
+        let sizes: Vec<usize> = by_size(&CONTAINERS, 25)
+            .map(|subset| subset.len())
+            .collect();
+        assert_eq!(sizes, [2, 2, 2, 3]);
+    }
+
+    #[test]
+    fn test_can_partition() {
+        let packages = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        assert!(can_partition(&packages, 3));
+        assert!(can_partition(&packages, 4));
+        assert!(!can_partition(&packages, 6));
+        assert!(!can_partition(&[6, 3, 3], 3));
+        assert!(can_partition(&[], 2));
+    }
+
+    #[test]
+    fn test_compositions() {
+        let all: Vec<Vec<u64>> = compositions(2, 3).collect();
+        assert_eq!(
+            all,
+            [
+                vec![0, 0, 2],
+                vec![0, 1, 1],
+                vec![0, 2, 0],
+                vec![1, 0, 1],
+                vec![1, 1, 0],
+                vec![2, 0, 0]
+            ]
+        );
+        assert_eq!(compositions(100, 4).count(), 176851);
+        assert_eq!(compositions(5, 1).collect::<Vec<_>>(), [vec![5]]);
+        assert_eq!(compositions(5, 0).count(), 0);
+    }
+}
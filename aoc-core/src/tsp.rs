@@ -0,0 +1,313 @@
+//! Travelling salesman over a dense distance matrix, solved exactly with
+//! Held-Karp in O(2ⁿ·n²) time and O(2ⁿ·n) memory, which is fine up to
+//! [`MAX_CITIES`] cities.
+//!
+//! ```ignore
+//! let mut distances = DistanceMatrix::new();
+//! distances.set("London", "Dublin", 464);
+//! let route = distances.solve(Shape::Path, Goal::Minimize)?;
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use crate::AocError;
+
+/// Whether the route returns to where it started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Path,
+    Cycle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Minimize,
+    Maximize,
+}
+
+impl Goal {
+    fn better(self, cost: i64, than: i64) -> bool {
+        match self {
+            Goal::Minimize => cost < than,
+            Goal::Maximize => cost > than,
+        }
+    }
+}
+
+/// Cities visited in order, each exactly once, and the total cost. A cycle
+/// starts at city 0 and its cost includes going back to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub cost: i64,
+    pub cities: Vec<usize>,
+}
+
+/// Why [`DistanceMatrix::solve`] found no route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TspError {
+    /// More than [`MAX_CITIES`] cities, too many to solve.
+    TooManyCities(usize),
+    /// No route visits every city once, including when there are none.
+    NoRoute,
+}
+
+impl fmt::Display for TspError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TspError::TooManyCities(count) => write!(
+                f,
+                "Can't plan a route through {} places, {} at most",
+                count, MAX_CITIES
+            ),
+            TspError::NoRoute => write!(f, "No route visits every place once"),
+        }
+    }
+}
+
+impl From<TspError> for AocError {
+    fn from(error: TspError) -> Self {
+        AocError::invalid(error.to_string())
+    }
+}
+
+/// Directed costs between cities, which are numbered in the order their names
+/// are first seen. Moves between cities without a cost are impossible.
+#[derive(Debug, Clone, Default)]
+pub struct DistanceMatrix {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    costs: Vec<Vec<Option<i64>>>,
+}
+
+/// Most cities [`DistanceMatrix::solve`] takes on, beyond which its tables
+/// would need gigabytes.
+pub const MAX_CITIES: usize = 20;
+
+/// Marks the start of a route in the parents table.
+const START: u8 = u8::MAX - 1;
+const UNREACHED: u8 = u8::MAX;
+
+impl DistanceMatrix {
+    pub fn new() -> Self {
+        DistanceMatrix::default()
+    }
+
+    /// Number of the city called `name`, adding it if it's new.
+    pub fn id(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        for row in &mut self.costs {
+            row.push(None);
+        }
+        self.costs.push(vec![None; id + 1]);
+        id
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Number of cities.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn cost(&self, from: usize, to: usize) -> Option<i64> {
+        self.costs[from][to]
+    }
+
+    /// Sets the cost of moving from `from` to `to`, but not back.
+    pub fn set(&mut self, from: &str, to: &str, cost: i64) {
+        let (from, to) = (self.id(from), self.id(to));
+        self.costs[from][to] = Some(cost);
+    }
+
+    /// Adds to the cost of moving from `from` to `to`, which starts at 0.
+    pub fn add(&mut self, from: &str, to: &str, cost: i64) {
+        let (from, to) = (self.id(from), self.id(to));
+        *self.costs[from][to].get_or_insert(0) += cost;
+    }
+
+    /// Cheapest or costliest route visiting every city once.
+    pub fn solve(&self, shape: Shape, goal: Goal) -> Result<Route, TspError> {
+        let count = self.len();
+        if count > MAX_CITIES {
+            return Err(TspError::TooManyCities(count));
+        }
+        if count == 0 {
+            return Err(TspError::NoRoute);
+        }
+
+        // best[mask * count + last] is the cost of the best route through the
+        // cities of `mask` ending at `last`, and parents has the city before it
+        let states = (1 << count) * count;
+        let mut best = vec![0; states];
+        let mut parents = vec![UNREACHED; states];
+        let starts = match shape {
+            Shape::Path => 0..count,
+            // a cycle may as well start at the first city
+            Shape::Cycle => 0..1,
+        };
+        for start in starts {
+            parents[(1 << start) * count + start] = START;
+        }
+
+        for mask in 1..1usize << count {
+            for last in 0..count {
+                let state = mask * count + last;
+                if parents[state] == UNREACHED {
+                    continue;
+                }
+                for next in (0..count).filter(|&next| mask & (1 << next) == 0) {
+                    let Some(step) = self.cost(last, next) else {
+                        continue;
+                    };
+                    let cost = best[state] + step;
+                    let next_state = (mask | 1 << next) * count + next;
+                    if parents[next_state] == UNREACHED || goal.better(cost, best[next_state]) {
+                        best[next_state] = cost;
+                        parents[next_state] = last as u8;
+                    }
+                }
+            }
+        }
+
+        let all = (1 << count) - 1;
+        let mut route: Option<(i64, usize)> = None;
+        for last in 0..count {
+            let state = all * count + last;
+            if parents[state] == UNREACHED {
+                continue;
+            }
+            let cost = match shape {
+                Shape::Path => best[state],
+                Shape::Cycle if count == 1 => best[state],
+                Shape::Cycle => match self.cost(last, 0) {
+                    Some(back) => best[state] + back,
+                    None => continue,
+                },
+            };
+            if route.is_none_or(|(best, _)| goal.better(cost, best)) {
+                route = Some((cost, last));
+            }
+        }
+
+        let (cost, mut city) = route.ok_or(TspError::NoRoute)?;
+        let mut cities = vec![];
+        let mut mask: usize = all;
+        loop {
+            cities.push(city);
+            let parent = parents[mask * count + city];
+            if parent == START {
+                break;
+            }
+            mask &= !(1 << city);
+            city = parent as usize;
+        }
+        cities.reverse();
+        Ok(Route { cost, cities })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route_names(distances: &DistanceMatrix, route: &Route) -> Vec<String> {
+        route
+            .cities
+            .iter()
+            .map(|&city| distances.name(city).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_paths() {
+        let mut distances = DistanceMatrix::new();
+        for (from, to, cost) in [
+            ("London", "Dublin", 464),
+            ("London", "Belfast", 518),
+            ("Dublin", "Belfast", 141),
+        ] {
+            distances.set(from, to, cost);
+            distances.set(to, from, cost);
+        }
+        assert_eq!(distances.len(), 3);
+
+        let shortest = distances.solve(Shape::Path, Goal::Minimize).unwrap();
+        assert_eq!(shortest.cost, 605);
+        let names = route_names(&distances, &shortest);
+        assert!(
+            names == ["London", "Dublin", "Belfast"] || names == ["Belfast", "Dublin", "London"]
+        );
+
+        let longest = distances.solve(Shape::Path, Goal::Maximize).unwrap();
+        assert_eq!(longest.cost, 982);
+        assert_eq!(longest.cities.len(), 3);
+
+        let cycle = distances.solve(Shape::Cycle, Goal::Minimize).unwrap();
+        assert_eq!((cycle.cost, cycle.cities[0]), (1123, 0));
+    }
+
+    #[test]
+    fn test_missing_moves() {
+        let mut distances = DistanceMatrix::new();
+        distances.set("a", "b", 2);
+        distances.set("b", "c", 1);
+        distances.add("c", "a", 2);
+        distances.add("c", "a", 3);
+        assert_eq!(distances.cost(2, 0), Some(5));
+
+        let path = distances.solve(Shape::Path, Goal::Maximize).unwrap();
+        assert_eq!(
+            path,
+            Route {
+                cost: 7,
+                cities: vec![2, 0, 1]
+            }
+        );
+        let cycle = distances.solve(Shape::Cycle, Goal::Minimize).unwrap();
+        assert_eq!(
+            cycle,
+            Route {
+                cost: 8,
+                cities: vec![0, 1, 2]
+            }
+        );
+
+        distances.id("d");
+        assert_eq!(
+            distances.solve(Shape::Path, Goal::Minimize),
+            Err(TspError::NoRoute)
+        );
+        assert_eq!(
+            DistanceMatrix::new().solve(Shape::Cycle, Goal::Minimize),
+            Err(TspError::NoRoute)
+        );
+    }
+
+    #[test]
+    fn test_too_many_cities() {
+        let mut distances = DistanceMatrix::new();
+        for city in 1..=MAX_CITIES + 1 {
+            distances.set(&(city - 1).to_string(), &city.to_string(), 1);
+        }
+        assert_eq!(distances.len(), MAX_CITIES + 2);
+        assert_eq!(
+            distances.solve(Shape::Path, Goal::Minimize),
+            Err(TspError::TooManyCities(MAX_CITIES + 2))
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Register machines running a program one instruction at a time, for the
+//! puzzles that come with their own little assembly language.
+//!
+//! Each language is an [`InstructionSet`]: an instruction type naming the
+//! machine's registers and executing itself against it, while [`Machine`]
+//! keeps the registers, the program counter and the output.
+//!
+//! ```ignore
+//! let mut machine = Machine::new(program).with_register("a", 1);
+//! machine.run();
+//! let b = machine.value("b");
+//! ```
+
+use std::fmt;
+
+use log::debug;
+
+use crate::parse::{Line, ParseError};
+
+/// A register of the machine, resolved from its name when parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Register(usize);
+
+/// An instruction argument that is either a register or a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+pub trait InstructionSet: Clone + fmt::Debug + Sized {
+    /// Names of the machine's registers, which all start at 0.
+    const REGISTERS: &'static [&'static str];
+
+    /// Executes the instruction, returning the offset of the next one from
+    /// it, so 1 carries on with the following instruction.
+    fn execute(&self, machine: &mut Machine<Self>) -> i64;
+
+    /// The register called `name`.
+    fn register(line: &Line<'_>, name: &str) -> Result<Register, ParseError> {
+        Self::REGISTERS
+            .iter()
+            .position(|register| *register == name)
+            .map(Register)
+            .ok_or_else(|| line.error(name, "Unknown register"))
+    }
+
+    /// A register name or an integer.
+    fn operand(line: &Line<'_>, text: &str) -> Result<Operand, ParseError> {
+        match text.parse() {
+            Ok(value) => Ok(Operand::Value(value)),
+            Err(_) => Self::register(line, text).map(Operand::Register),
+        }
+    }
+}
+
+/// A program with its registers, which halts when the program counter leaves
+/// the program or an instruction fails.
+#[derive(Debug, Clone)]
+pub struct Machine<I> {
+    /// Instructions may change the program itself.
+    pub program: Vec<I>,
+    /// Index of the next instruction.
+    pub counter: i64,
+    registers: Vec<i64>,
+    /// Values the program has sent out, in order.
+    pub output: Vec<i64>,
+    fault: Option<String>,
+}
+
+impl<I: InstructionSet> Machine<I> {
+    pub fn new(program: Vec<I>) -> Self {
+        Machine {
+            program,
+            counter: 0,
+            registers: vec![0; I::REGISTERS.len()],
+            output: vec![],
+            fault: None,
+        }
+    }
+
+    /// Starts with `value` in the register called `name` instead of 0.
+    pub fn with_register(mut self, name: &str, value: i64) -> Self {
+        let index = I::REGISTERS
+            .iter()
+            .position(|register| *register == name)
+            .unwrap_or_else(|| panic!("There's no register {}", name));
+        self.registers[index] = value;
+        self
+    }
+
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register.0]
+    }
+
+    pub fn set(&mut self, register: Register, value: i64) {
+        self.registers[register.0] = value;
+    }
+
+    /// Value of the register called `name`, if there's one.
+    pub fn value(&self, name: &str) -> Option<i64> {
+        let index = I::REGISTERS.iter().position(|register| *register == name)?;
+        Some(self.registers[index])
+    }
+
+    pub fn read(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// Halts the machine because the current instruction can't be executed,
+    /// e.g. when a register would overflow.
+    pub fn fail(&mut self, message: impl Into<String>) {
+        self.fault = Some(message.into());
+    }
+
+    /// Why the machine halted, if an instruction failed.
+    pub fn fault(&self) -> Option<&str> {
+        self.fault.as_deref()
+    }
+
+    /// The instruction at the program counter, if it's still in the program
+    /// and nothing failed.
+    pub fn current(&self) -> Option<&I> {
+        if self.fault.is_some() {
+            return None;
+        }
+        usize::try_from(self.counter)
+            .ok()
+            .and_then(|counter| self.program.get(counter))
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.current().is_none()
+    }
+
+    /// Executes the next instruction, returning false if the machine had
+    /// already halted.
+    pub fn step(&mut self) -> bool {
+        let Some(instruction) = self.current().cloned() else {
+            return false;
+        };
+        debug!(
+            "Registers = {:?}; PC = {} => {:?}",
+            self.registers, self.counter, instruction
+        );
+        let offset = instruction.execute(self);
+        // leave the counter on the instruction that failed
+        if self.fault.is_none() {
+            self.counter += offset;
+        }
+        true
+    }
+
+    /// Runs until the program halts, which might be never.
+    pub fn run(&mut self) {
+        while self.step() {}
+        debug!("Halted with registers = {:?}", self.registers);
+    }
+
+    /// Runs for at most `steps` instructions, returning whether the program
+    /// halted in the meantime.
+    pub fn run_for(&mut self, steps: usize) -> bool {
+        for _ in 0..steps {
+            if !self.step() {
+                return true;
+            }
+        }
+        self.is_halted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    /// Enough assembunny to check that another instruction set plugs in.
+    #[derive(Debug, Clone)]
+    enum Assembunny {
+        Cpy(Operand, Register),
+        Inc(Register),
+        Dec(Register),
+        Jnz(Operand, Operand),
+        Out(Operand),
+    }
+
+    impl InstructionSet for Assembunny {
+        const REGISTERS: &'static [&'static str] = &["a", "b", "c", "d"];
+
+        fn execute(&self, machine: &mut Machine<Self>) -> i64 {
+            match *self {
+                Assembunny::Cpy(from, to) => machine.set(to, machine.read(from)),
+                Assembunny::Inc(register) => match machine.get(register).checked_add(1) {
+                    Some(value) => machine.set(register, value),
+                    None => machine.fail("Overflow"),
+                },
+                Assembunny::Dec(register) => machine.set(register, machine.get(register) - 1),
+                Assembunny::Jnz(value, offset) => {
+                    if machine.read(value) != 0 {
+                        return machine.read(offset);
+                    }
+                }
+                Assembunny::Out(value) => machine.output.push(machine.read(value)),
+            }
+            1
+        }
+    }
+
+    fn parse_assembunny(line: Line) -> Result<Assembunny, ParseError> {
+        let words: Vec<&str> = line.words().collect();
+        match words[..] {
+            ["cpy", from, to] => Ok(Assembunny::Cpy(
+                Assembunny::operand(&line, from)?,
+                Assembunny::register(&line, to)?,
+            )),
+            ["inc", register] => Ok(Assembunny::Inc(Assembunny::register(&line, register)?)),
+            ["dec", register] => Ok(Assembunny::Dec(Assembunny::register(&line, register)?)),
+            ["jnz", value, offset] => Ok(Assembunny::Jnz(
+                Assembunny::operand(&line, value)?,
+                Assembunny::operand(&line, offset)?,
+            )),
+            ["out", value] => Ok(Assembunny::Out(Assembunny::operand(&line, value)?)),
+            _ => Err(line.error(line.text, "Invalid instruction")),
+        }
+    }
+
+    fn load(program: &str) -> Machine<Assembunny> {
+        Machine::new(parse::parse_lines(program, parse_assembunny).unwrap())
+    }
+
+    #[test]
+    fn test_run() {
+        let mut machine = load("cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a");
+        machine.run();
+        assert_eq!(machine.value("a"), Some(42));
+        assert_eq!(machine.value("e"), None);
+        assert!(machine.is_halted());
+        assert!(!machine.step());
+
+        let mut machine = load("cpy c b\ncpy 3 a\nout b\ndec a\njnz a -2").with_register("c", 7);
+        machine.run();
+        assert_eq!(machine.output, [7, 7, 7]);
+        assert_eq!(machine.counter, 5);
+    }
+
+    #[test]
+    fn test_run_for() {
+        let mut machine = load("inc a\nout a\njnz 1 -2");
+        assert!(!machine.run_for(10));
+        assert_eq!(machine.output, [1, 2, 3]);
+        assert_eq!(machine.value("a"), Some(4));
+        assert_eq!(machine.counter, 1);
+
+        let mut machine = load("inc a\ninc b").with_register("a", i64::MAX);
+        machine.run();
+        assert_eq!(machine.fault(), Some("Overflow"));
+        assert_eq!((machine.counter, machine.value("b")), (0, Some(0)));
+        assert!(machine.is_halted());
+
+        assert!(parse::parse_lines("inc e", parse_assembunny).is_err());
+        assert!(parse::parse_lines("cpy 1 2", parse_assembunny).is_err());
+    }
+}